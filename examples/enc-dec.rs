@@ -50,9 +50,9 @@ fn main() {
 
     // Words, broken with wrong keyboard layout
     //    table(&morph_ru, "задaч");
-    //    'ru:f:peзaть,3;ks:'
-    //    'ru:f:мoжeт,3;ks:'
-    //    'ru:f:болeе,b4;ks:'
-    //    'ru:f:стeпeнь,3;ks:'
-    //    'ru:f:pешeние,4c;ks:ие'
+    //    'ru:r:peзaть,d:резать,76a'
+    //    'ru:r:мoжeт,d:может,7bb,5'
+    //    'ru:r:болeе,d:более,3'
+    //    'ru:r:стeпeнь,d:степень,d'
+    //    'ru:r:pешeние,d:решение,4c'
 }
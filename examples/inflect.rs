@@ -17,7 +17,7 @@ fn main() {
 
     let apple = Lex::from_id(&morph_ru, "ru:d:яблоко,22c").unwrap();
     let bread = Lex::from_id(&morph_ru, "ru:d:хлеб,878").unwrap();
-    // let fish = Lex::from_id(&morph_ru, "ru:d:рыба,35").unwrap();
+    let fish = Lex::from_id(&morph_ru, "ru:d:рыба,35").unwrap();
 
     let ablt_set = GrammemeSet::new("ablt");
 
@@ -57,11 +57,18 @@ fn main() {
             .get_plural(&morph_ru, 2)
             .unwrap(),
     );
-    // FIXME рыбою => рыбами
-    // println!(
-    //    " ::: накормил {} {} и {} {}",
-    //    2, bread.inflect(&morph_ru, &ablt_set).unwrap().get_plural(&morph_ru,
-    // 2).unwrap(),    5, fish.inflect(&morph_ru,
-    // &ablt_set).unwrap().get_plural(&morph_ru, 5).unwrap(),
-    //);
+    println!(
+        " ::: накормил {} {} и {} {}",
+        2,
+        bread
+            .inflect(&morph_ru, &ablt_set)
+            .unwrap()
+            .get_plural(&morph_ru, 2)
+            .unwrap(),
+        5,
+        fish.inflect(&morph_ru, &ablt_set)
+            .unwrap()
+            .get_plural(&morph_ru, 5)
+            .unwrap(),
+    );
 }
@@ -12,7 +12,7 @@ pub fn print_row_parsed(morph: &MorphAnalyzer, n: usize, &Parsed { ref lex, scor
         score = score.value(),
         lex = lex.get_word(),
         norm = lex.get_normal_form(morph),
-        tag = lex.get_tag(morph).string.as_str(),
+        tag = morph.tag_string(lex.get_tag(morph)),
         enc = lex.stack.encoded()
     );
 }
@@ -24,7 +24,7 @@ pub fn print_row_lex(morph: &MorphAnalyzer, n: usize, lex: &Lex) {
         score = Score::Fake(0.0).value(), // lex.score.value(),
         lex = lex.get_word(),
         norm = lex.get_normal_form(morph),
-        tag = lex.get_tag(morph).string.as_str(),
+        tag = morph.tag_string(lex.get_tag(morph)),
         enc = lex.stack.encoded()
     );
 }
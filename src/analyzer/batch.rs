@@ -0,0 +1,55 @@
+use std::cmp::min;
+use std::num::NonZeroUsize;
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+/// Words are handed out to workers by chunks of this size.
+const CHUNK_LEN: usize = 64;
+
+/// Maps `items` on up to `threads` scoped workers (`0` means one per CPU)
+/// and returns the results in the order of `items`.
+///
+/// Workers take chunks from a shared counter, so a chunk of long words
+/// doesn't hold up the others.
+pub fn map_ordered<T, R, F>(items: &[T], threads: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let threads = match threads {
+        0 => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+        threads => threads,
+    };
+    let chunks_count = items.len().div_ceil(CHUNK_LEN);
+    let threads = min(threads, chunks_count);
+    if threads <= 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let next_chunk = AtomicUsize::new(0);
+    let worker = || {
+        let mut done = Vec::new();
+        loop {
+            let idx = next_chunk.fetch_add(1, Ordering::Relaxed);
+            if idx >= chunks_count {
+                break done;
+            }
+            let chunk = &items[idx * CHUNK_LEN..min((idx + 1) * CHUNK_LEN, items.len())];
+            done.push((idx, chunk.iter().map(&f).collect::<Vec<R>>()));
+        }
+    };
+    let mut chunks: Vec<(usize, Vec<R>)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads).map(|_| scope.spawn(worker)).collect();
+        workers
+            .into_iter()
+            .flat_map(|w| w.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+            .collect()
+    });
+    chunks.sort_unstable_by_key(|&(idx, _)| idx);
+    chunks
+        .into_iter()
+        .flat_map(|(_, results)| results)
+        .collect()
+}
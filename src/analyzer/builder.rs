@@ -0,0 +1,182 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::analyzer::cache::{self, ParseCache};
+use crate::analyzer::morph::Units;
+use crate::analyzer::pipeline::{Pipeline, UnitKind};
+use crate::analyzer::substitutes::CharSubstitutes;
+use crate::analyzer::units::abc::AnalyzerUnit;
+use crate::analyzer::variant::VariantPolicy;
+use crate::analyzer::MorphAnalyzer;
+use crate::estimator::SingleTagProbabilityEstimator;
+use crate::opencorpora::dictionary::Dictionary;
+use crate::opencorpora::error::DictionaryError;
+use crate::opencorpora::tag::Tagset;
+
+/// Configures units of `MorphAnalyzer` and the order they are applied in.
+///
+/// ```
+/// use rsmorphy::analyzer::{MorphAnalyzerBuilder, UnitKind};
+///
+/// // Dictionary-only lemmatization: no guessing by prefixes or suffixes.
+/// let morph = MorphAnalyzerBuilder::new()
+///     .without(UnitKind::Kp)
+///     .without(UnitKind::Up)
+///     .without(UnitKind::Ks)
+///     .build_from_file(rsmorphy::dict_ru::DICT_PATH);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct MorphAnalyzerBuilder {
+    units: Units,
+    pipeline: Pipeline,
+    threads: usize,
+    cache: Option<Arc<ParseCache>>,
+    char_substitutes: CharSubstitutes,
+    tagset: Tagset,
+    variant_policy: VariantPolicy,
+}
+
+impl MorphAnalyzerBuilder {
+    pub fn new() -> Self {
+        MorphAnalyzerBuilder::default()
+    }
+
+    /// Replaces units with custom configured ones.
+    pub fn units(mut self, units: Units) -> Self {
+        self.units = units;
+        self
+    }
+
+    /// Registers a user-defined unit; add it to the pipeline
+    /// with `UnitKind::Custom(name)`.
+    pub fn register<U>(mut self, name: &'static str, unit: U) -> Self
+    where
+        U: AnalyzerUnit + Send + Sync + 'static,
+    {
+        self.units.custom.register(name, unit);
+        self
+    }
+
+    /// Replaces the whole pipeline.
+    pub fn pipeline(mut self, pipeline: Pipeline) -> Self {
+        self.pipeline = pipeline;
+        self
+    }
+
+    /// Adds the `unit` to the end of the pipeline (or updates its `stop` flag).
+    pub fn with(mut self, unit: UnitKind, stop: bool) -> Self {
+        self.pipeline.enable(unit, stop);
+        self
+    }
+
+    /// Adds the `unit` right before `before`.
+    pub fn with_before(mut self, unit: UnitKind, before: UnitKind, stop: bool) -> Self {
+        self.pipeline.insert_before(unit, before, stop);
+        self
+    }
+
+    /// Removes the `unit` from the pipeline.
+    pub fn without(mut self, unit: UnitKind) -> Self {
+        self.pipeline.disable(unit);
+        self
+    }
+
+    /// Sets whether the search stops after the `unit` if there are results.
+    pub fn stop_after(mut self, unit: UnitKind, stop: bool) -> Self {
+        self.pipeline.set_stop(unit, stop);
+        self
+    }
+
+    /// Rearranges units in the given order; unlisted units are removed.
+    pub fn order(mut self, order: &[UnitKind]) -> Self {
+        self.pipeline.reorder(order);
+        self
+    }
+
+    /// Sets the number of workers for batch methods; `0` (the default)
+    /// means one per CPU.
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    /// Caches results of `parse` for up to `capacity` most recently used words.
+    pub fn cache(mut self, capacity: usize) -> Self {
+        self.cache = Some(Arc::new(ParseCache::new(capacity)));
+        self
+    }
+
+    /// Caches results of `parse` in the cache of other analyzers;
+    /// they don't take results of each other.
+    pub fn shared_cache(mut self, cache: Arc<ParseCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Overrides the letter substitutes of the dictionary.
+    pub fn char_substitutes(mut self, char_substitutes: CharSubstitutes) -> Self {
+        self.char_substitutes = char_substitutes;
+        self
+    }
+
+    /// Sets the spelling of tags returned by `MorphAnalyzer::tag_string`;
+    /// both spellings are loaded with the dictionary anyway.
+    pub fn tagset(mut self, tagset: Tagset) -> Self {
+        self.tagset = tagset;
+        self
+    }
+
+    /// Sets which forms `Lex::inflect` takes when several fit equally well.
+    pub fn variant_policy(mut self, variant_policy: VariantPolicy) -> Self {
+        self.variant_policy = variant_policy;
+        self
+    }
+
+    /// Creates `MorphAnalyzer` with preloaded dict; pass `Arc<Dictionary>`
+    /// to share the dict with other analyzers.
+    pub fn build<D>(self, dict: D) -> MorphAnalyzer
+    where
+        D: Into<Arc<Dictionary>>,
+    {
+        let MorphAnalyzerBuilder {
+            units,
+            pipeline,
+            threads,
+            cache,
+            char_substitutes,
+            tagset,
+            variant_policy,
+        } = self;
+        let dict = dict.into();
+        let estimator = SingleTagProbabilityEstimator {};
+        MorphAnalyzer {
+            dict,
+            estimator,
+            units,
+            pipeline,
+            threads,
+            cache,
+            config: cache::next_config(),
+            char_substitutes,
+            tagset,
+            variant_policy,
+        }
+    }
+
+    /// Loads `Dictionary` from disk and creates `MorphAnalyzer`
+    pub fn build_from_file<P>(self, p: P) -> MorphAnalyzer
+    where
+        P: AsRef<Path>,
+    {
+        self.build(Dictionary::from_file(p))
+    }
+
+    /// Loads `Dictionary` from disk and creates `MorphAnalyzer`;
+    /// fails if any of the dictionary files is missing or broken.
+    pub fn try_build_from_file<P>(self, p: P) -> Result<MorphAnalyzer, DictionaryError>
+    where
+        P: AsRef<Path>,
+    {
+        Ok(self.build(Dictionary::try_from_file(p)?))
+    }
+}
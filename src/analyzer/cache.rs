@@ -0,0 +1,174 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, Weak};
+
+use crate::analyzer::MorphAnalyzer;
+use crate::container::ParseResult;
+use crate::opencorpora::dictionary::Dictionary;
+
+/// Counters of a `ParseCache`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub len: usize,
+    pub capacity: usize,
+}
+
+/// Assigned to each built `MorphAnalyzer`, see `next_config`.
+static NEXT_CONFIG: AtomicU64 = AtomicU64::new(0);
+
+/// A new identifier of an analyzer configuration, which results of `parse`
+/// are cached under; clones of the analyzer keep it.
+pub(crate) fn next_config() -> u64 {
+    NEXT_CONFIG.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Cached results of one analyzer configuration.
+#[derive(Debug)]
+struct Entries {
+    dict: Weak<Dictionary>,
+    /// Word → (result, the tick of the last use)
+    words: HashMap<String, (ParseResult, u64)>,
+}
+
+#[derive(Debug, Default)]
+struct Lru {
+    configs: HashMap<u64, Entries>,
+    /// The tick of the last use → (configuration, word), the least recently
+    /// used first
+    order: BTreeMap<u64, (u64, String)>,
+    tick: u64,
+}
+
+impl Lru {
+    fn touch(&mut self, config: u64, word: &str) -> Option<&ParseResult> {
+        self.tick += 1;
+        let (result, last_used) = self.configs.get_mut(&config)?.words.get_mut(word)?;
+        let key = self.order.remove(last_used).unwrap();
+        *last_used = self.tick;
+        self.order.insert(self.tick, key);
+        Some(result)
+    }
+
+    fn remove(&mut self, config: u64, word: &str) {
+        if let Some(entries) = self.configs.get_mut(&config) {
+            entries.words.remove(word);
+            if entries.words.is_empty() {
+                self.configs.remove(&config);
+            }
+        }
+    }
+
+    /// Drops the entries of analyzers whose dictionary is gone.
+    fn drop_dead(&mut self) {
+        let configs = &mut self.configs;
+        configs.retain(|_, entries| entries.dict.strong_count() > 0);
+        let alive = |_: &u64, (config, _): &mut (u64, String)| configs.contains_key(config);
+        self.order.retain(alive);
+    }
+
+    fn len(&self) -> usize {
+        self.order.len()
+    }
+}
+
+/// A bounded cache of parse results keyed by the raw word, which evicts
+/// the least recently used entries.
+///
+/// It is shared between clones of `MorphAnalyzer` and analyzers built with
+/// `MorphAnalyzerBuilder::shared_cache`; each built analyzer has separate
+/// entries, so change the configuration with the builder, not by hand.
+pub struct ParseCache {
+    capacity: usize,
+    lru: Mutex<Lru>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl ParseCache {
+    pub fn new(capacity: usize) -> Self {
+        ParseCache {
+            capacity,
+            lru: Mutex::default(),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Lru> {
+        // The cache stays consistent even if a thread panicked while holding it.
+        self.lru.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the result cached for the word by the analyzer (or its clones),
+    /// or computes it with `parse` and caches it.
+    pub fn get_or_insert_with<F>(&self, morph: &MorphAnalyzer, word: &str, parse: F) -> ParseResult
+    where
+        F: FnOnce() -> ParseResult,
+    {
+        {
+            let mut lru = self.lock();
+            if let Some(result) = lru.touch(morph.config, word) {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                return result.clone();
+            }
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+
+        // Parse without the lock, so other threads aren't blocked meanwhile.
+        let result = parse();
+        if self.capacity > 0 {
+            self.insert(morph, word, result.clone());
+        }
+        result
+    }
+
+    fn insert(&self, morph: &MorphAnalyzer, word: &str, result: ParseResult) {
+        let mut lru = self.lock();
+        if lru.touch(morph.config, word).is_some() {
+            // Another thread has already cached the word.
+            return;
+        }
+        if lru.len() >= self.capacity {
+            if let Some((_, (oldest_config, oldest))) = lru.order.pop_first() {
+                lru.remove(oldest_config, &oldest);
+            }
+        }
+        if !lru.configs.contains_key(&morph.config) {
+            lru.drop_dead();
+        }
+        let tick = lru.tick;
+        lru.order.insert(tick, (morph.config, word.to_owned()));
+        let entries = lru.configs.entry(morph.config).or_insert_with(|| Entries {
+            dict: Arc::downgrade(&morph.dict),
+            words: HashMap::new(),
+        });
+        entries.words.insert(word.to_owned(), (result, tick));
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            len: self.lock().len(),
+            capacity: self.capacity,
+        }
+    }
+
+    /// Drops all entries and resets the counters.
+    pub fn clear(&self) {
+        *self.lock() = Lru::default();
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+    }
+}
+
+impl fmt::Debug for ParseCache {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ParseCache")
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
@@ -1,4 +1,15 @@
+pub mod batch;
+pub mod builder;
+pub mod cache;
 pub mod morph;
+pub mod pipeline;
+pub mod substitutes;
 pub mod units;
+pub mod variant;
 
+pub use self::builder::MorphAnalyzerBuilder;
+pub use self::cache::{CacheStats, ParseCache};
 pub use self::morph::MorphAnalyzer;
+pub use self::pipeline::{Pipeline, Step, UnitKind};
+pub use self::substitutes::CharSubstitutes;
+pub use self::variant::VariantPolicy;
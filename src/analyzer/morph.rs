@@ -1,13 +1,28 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashSet};
 use std::path::Path;
+use std::sync::Arc;
 
+use crate::analyzer::batch::map_ordered;
+use crate::analyzer::builder::MorphAnalyzerBuilder;
+use crate::analyzer::cache::{CacheStats, ParseCache};
+use crate::analyzer::pipeline::{Pipeline, UnitKind};
+use crate::analyzer::substitutes::CharSubstitutes;
 use crate::analyzer::units::*;
+use crate::analyzer::variant::VariantPolicy;
+use crate::container::abc::*;
 use crate::container::{ParseResult, SeenSet};
+use crate::dawg::HH;
 use crate::estimator::SingleTagProbabilityEstimator;
 use crate::opencorpora::dictionary::Dictionary;
+use crate::opencorpora::error::DictionaryError;
+use crate::opencorpora::query::{TagQuery, TagQueryError};
+use crate::opencorpora::{OpencorporaTagReg, Tagset};
 
 #[derive(Debug, Default, Clone)]
 pub struct Units {
     pub dictionary: DictionaryAnalyzer,
+    pub homoglyph: HomoglyphAnalyzer,
     pub initials: InitialsAnalyzer,
     pub latin: LatinAnalyzer,
     pub number: NumberAnalyzer,
@@ -20,25 +35,61 @@ pub struct Units {
     pub ks: KnownSuffixAnalyzer,
     pub up: UnknownPrefixAnalyzer,
     pub unknown: UnknownAnalyzer,
+    pub custom: UnitRegistry,
 }
 
+impl Units {
+    /// Returns `None` for a custom unit which is not registered.
+    pub fn get(&self, kind: UnitKind) -> Option<&dyn AnalyzerUnit> {
+        Some(match kind {
+            UnitKind::Dictionary => &self.dictionary,
+            UnitKind::Homoglyph => &self.homoglyph,
+            UnitKind::Initials => &self.initials,
+            UnitKind::Number => &self.number,
+            UnitKind::Punct => &self.punct,
+            UnitKind::Roman => &self.roman,
+            UnitKind::Latin => &self.latin,
+            UnitKind::Hsp => &self.hsp,
+            UnitKind::Ha => &self.ha,
+            UnitKind::Hword => &self.hword,
+            UnitKind::Kp => &self.kp,
+            UnitKind::Up => &self.up,
+            UnitKind::Ks => &self.ks,
+            UnitKind::Unknown => &self.unknown,
+            UnitKind::Custom(name) => self.custom.get(name)?,
+        })
+    }
+}
+
+/// The analyzer is `Send + Sync`, and its clones share the dictionary,
+/// so it's cheap to hand one to each thread.
 #[derive(Debug, Clone)]
 pub struct MorphAnalyzer {
-    pub dict: Dictionary,
+    pub dict: Arc<Dictionary>,
     pub estimator: SingleTagProbabilityEstimator,
     pub units: Units,
+    pub pipeline: Pipeline,
+    /// Workers for batch methods; `0` means one per CPU.
+    pub threads: usize,
+    /// Results of `parse` are cached under `config`.
+    pub cache: Option<Arc<ParseCache>>,
+    /// Identifies the configuration of the analyzer in the cache;
+    /// assigned by `MorphAnalyzerBuilder::build` and kept by clones.
+    pub(crate) config: u64,
+    pub char_substitutes: CharSubstitutes,
+    /// The spelling of tags returned by `tag_string`.
+    pub tagset: Tagset,
+    /// Which forms `Lex::inflect` takes when several fit equally well.
+    pub variant_policy: VariantPolicy,
 }
 
 impl MorphAnalyzer {
     /// Creates `MorphAnalyzer` with preloaded dict
-    pub fn new(dict: Dictionary) -> Self {
-        let estimator = SingleTagProbabilityEstimator {};
-        let units = Units::default();
-        MorphAnalyzer {
-            dict,
-            estimator,
-            units,
-        }
+    pub fn new<D>(dict: D) -> Self
+    where
+        D: Into<Arc<Dictionary>>,
+    {
+        MorphAnalyzerBuilder::new().build(dict)
     }
 
     /// Loads `Dictionary` from disk and creates `MorphAnalyzer`
@@ -47,70 +98,261 @@ impl MorphAnalyzer {
         P: AsRef<Path>,
     {
         let dict = Dictionary::from_file(p);
-        // TODO consider move into dict?
-        // char_substitutes = dictionary.words.compile_replaces(char_substitutes or {})
         MorphAnalyzer::new(dict)
     }
 
+    /// Loads `Dictionary` from disk and creates `MorphAnalyzer`;
+    /// fails if any of the dictionary files is missing or broken.
+    pub fn try_from_file<P>(p: P) -> Result<Self, DictionaryError>
+    where
+        P: AsRef<Path>,
+    {
+        Dictionary::try_from_file(p).map(MorphAnalyzer::new)
+    }
+
+    /// Maps the dictionary bundle and creates `MorphAnalyzer`
+    pub fn from_bundle<P>(p: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        MorphAnalyzer::new(Dictionary::from_bundle(p))
+    }
+
+    /// Maps the dictionary bundle and creates `MorphAnalyzer`;
+    /// fails if the bundle is missing or broken.
+    pub fn try_from_bundle<P>(p: P) -> Result<Self, DictionaryError>
+    where
+        P: AsRef<Path>,
+    {
+        Dictionary::try_from_bundle(p).map(MorphAnalyzer::new)
+    }
+
+    pub fn builder() -> MorphAnalyzerBuilder {
+        MorphAnalyzerBuilder::new()
+    }
+
     /// Analyze the word and return a list of `Parsed`:
     pub fn parse(&self, word: &str) -> ParseResult {
+        match self.cache {
+            Some(ref cache) => cache.get_or_insert_with(self, word, || self.parse_uncached(word)),
+            None => self.parse_uncached(word),
+        }
+    }
+
+    /// The same as `parse`, but bypasses the cache.
+    pub fn parse_uncached(&self, word: &str) -> ParseResult {
         let word_lower = word.to_lowercase();
 
-        let look_over = || -> ParseResult {
-            let mut result = ParseResult::new();
-            let mut seen = SeenSet::default();
-
-            macro_rules! look_in (
-                ($t: ident) => {{
-                    self.units.$t.parse(self, &mut result, word, &word_lower, &mut seen);
-                }};
-                ($t: ident, return) => {{
-                    self.units.$t.parse(self, &mut result, word, &word_lower, &mut seen);
-                    if !result.is_empty() { return result };
-                }}
-            );
+        let mut result = ParseResult::new();
+        let mut seen = SeenSet::default();
 
-            look_in!(dictionary);
-            look_in!(initials, return);
+        for step in &self.pipeline.steps {
+            let unit = match self.units.get(step.unit) {
+                Some(unit) => unit,
+                None => continue,
+            };
+            unit.parse(self, &mut result, word, &word_lower, &mut seen);
+            if step.stop && !result.is_empty() {
+                break;
+            }
+        }
+
+        self.estimator
+            .apply_to_parses(self, word, &word_lower, &mut result);
+        result
+    }
 
-            look_in!(number, return);
+    /// Parses of the word with tags matching the query, like
+    /// "NOUN & (nomn | accs) & !plur", see `TagQuery`.
+    ///
+    /// ```
+    /// use rsmorphy::prelude::*;
+    ///
+    /// let morph = MorphAnalyzer::from_file(rsmorphy::dict_ru::DICT_PATH);
+    /// let parsed = morph.parse_filtered("стали", "VERB & plur").unwrap();
+    /// assert!(!parsed.is_empty());
+    /// assert!(parsed.iter().all(|p| p.lex.get_tag(&morph).has("VERB")));
+    /// assert!(morph.parse_filtered("стали", "VERB & plurr").is_err());
+    /// ```
+    pub fn parse_filtered(&self, word: &str, query: &str) -> Result<ParseResult, TagQueryError> {
+        let query = TagQuery::parse(query)?;
+        query.check(&self.dict)?;
+        Ok(self.parse_matching(word, &query))
+    }
 
-            look_in!(punct, return);
+    /// Like `parse_filtered` with a query parsed once for many words;
+    /// its grammemes aren't checked, see `TagQuery::check`.
+    pub fn parse_matching(&self, word: &str, query: &TagQuery) -> ParseResult {
+        let mut result = self.parse(word);
+        result.retain(|parsed| parsed.lex.get_tag(self).matches(query));
+        result
+    }
 
-            look_in!(roman);
-            look_in!(latin, return);
+    /// Returns possible tags of the word, the most probable first.
+    ///
+    /// It's faster than `parse`, as lexemes are not built where units can
+    /// find tags without them.
+    pub fn tag(&self, word: &str) -> Vec<&OpencorporaTagReg> {
+        let word_lower = word.to_lowercase();
 
-            look_in!(hsp, return);
+        let mut result = Vec::new();
+        let mut seen = SeenSet::default();
 
-            look_in!(ha, return);
+        for step in &self.pipeline.steps {
+            let unit = match self.units.get(step.unit) {
+                Some(unit) => unit,
+                None => continue,
+            };
+            unit.tag(self, &mut result, word, &word_lower, &mut seen);
+            if step.stop && !result.is_empty() {
+                break;
+            }
+        }
 
-            look_in!(hword, return);
+        self.estimator
+            .apply_to_tags(self, word, &word_lower, &mut result);
+        let mut seen_tags = HashSet::new();
+        result.retain(|tag| seen_tags.insert(tag.string.as_str()));
+        result
+    }
 
-            look_in!(kp, return);
+    /// Returns normal forms of all parses of the word without duplicates,
+    /// the most probable first.
+    pub fn normal_forms(&self, word: &str) -> Vec<String> {
+        let mut result: Vec<String> = Vec::new();
+        for parsed in self.parse(word) {
+            let normal_form = parsed.lex.get_normal_form(self);
+            if !result.iter().any(|nf| *nf == normal_form) {
+                result.push(normal_form.into_owned());
+            }
+        }
+        result
+    }
 
-            look_in!(up);
-            look_in!(ks, return);
+    /// The tag spelled in the tagset of the analyzer.
+    pub fn tag_string<'t>(&self, tag: &'t OpencorporaTagReg) -> Cow<'t, str> {
+        tag.get_string(self.tagset, &self.dict)
+    }
 
-            look_in!(unknown, return);
+    /// Letters of a word which may stand for other letters in the dictionary.
+    pub fn substitutes(&self) -> &BTreeMap<String, String> {
+        self.char_substitutes.get(&self.dict)
+    }
 
-            unreachable!();
+    /// Checks if the word is in the dictionary; guessers are not used.
+    ///
+    /// With `strict_ee`, "е" and "ё" don't stand for each other; other
+    /// substitutes of the analyzer still apply.
+    pub fn word_is_known(&self, word: &str, strict_ee: bool) -> bool {
+        let word_lower = word.to_lowercase();
+        let without_ee: BTreeMap<String, String>;
+        let char_substitutes = match strict_ee {
+            true => {
+                without_ee = self
+                    .substitutes()
+                    .iter()
+                    .filter(|&(from, to)| !matches!((&from[..], &to[..]), ("е", "ё") | ("ё", "е")))
+                    .map(|(from, to)| (from.clone(), to.clone()))
+                    .collect();
+                &without_ee
+            }
+            false => self.substitutes(),
         };
+        !self
+            .dict
+            .words
+            .similar_items(&word_lower, char_substitutes)
+            .is_empty()
+    }
 
-        let mut result = look_over();
-        self.estimator
-            .apply_to_parses(self, word, &word_lower, &mut result);
+    /// Returns up to `limit` dictionary word forms starting with the `prefix`,
+    /// each with its distinct normal forms.
+    pub fn complete(&self, prefix: &str, limit: usize) -> Vec<(String, Vec<String>)> {
+        let mut result: Vec<(String, Vec<String>)> = Vec::new();
+        for (word, HH(para_id, idx)) in self.dict.words.items_with_prefix(&prefix.to_lowercase()) {
+            let normal_form = self
+                .dict
+                .build_normal_form(para_id.into(), idx.into(), &word)
+                .into_owned();
+            match result.last_mut() {
+                Some((last_word, normal_forms)) if *last_word == word => {
+                    if !normal_forms.contains(&normal_form) {
+                        normal_forms.push(normal_form);
+                    }
+                    continue;
+                }
+                _ => (),
+            }
+            if result.len() == limit {
+                break;
+            }
+            result.push((word, vec![normal_form]));
+        }
         result
     }
+
+    /// Returns `None` if the analyzer has no cache.
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.cache.as_ref().map(|cache| cache.stats())
+    }
+
+    /// Parses the words in parallel; results are in the order of `words`.
+    pub fn parse_batch<S>(&self, words: &[S]) -> Vec<ParseResult>
+    where
+        S: AsRef<str> + Sync,
+    {
+        map_ordered(words, self.threads, |word| self.parse(word.as_ref()))
+    }
+
+    /// Returns the normal form of the most probable parse of each word,
+    /// or the lowercased word if it has no parses.
+    pub fn lemmatize_batch<S>(&self, words: &[S]) -> Vec<String>
+    where
+        S: AsRef<str> + Sync,
+    {
+        map_ordered(words, self.threads, |word| {
+            let word = word.as_ref();
+            match self.parse(word).first() {
+                Some(parsed) => parsed.lex.get_normal_form(self).into_owned(),
+                None => word.to_lowercase(),
+            }
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::io::{Read, Write};
+    use std::path::Path;
+    use std::sync::Arc;
+    use std::{env, fs, process, thread};
+
     use env_logger;
+    use flate2::read::GzDecoder;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use maplit::btreemap;
+    use serde_json::{json, Value};
 
-    use crate::MorphAnalyzer;
+    use crate::analyzer::units::abc::AnalyzerUnit;
+    use crate::analyzer::{CharSubstitutes, ParseCache, Pipeline, UnitKind, VariantPolicy};
+    use crate::bundle;
+    use crate::container::abc::*;
+    use crate::container::stack::StackSource;
+    use crate::container::{
+        Custom, InflectError, ParseResult, Parsed, Pymorphy2Parse, Score, SeenSet, WordStruct,
+    };
+    use crate::opencorpora::kind::{Flag, FlagGroup};
+    use crate::opencorpora::query::{TagQuery, TagQueryError};
+    use crate::opencorpora::{DictionaryError, Grammeme, GrammemeSet, OpencorporaTagReg, Tagset};
+    use crate::{Language, Lex, MorphAnalyzer};
 
     lazy_static::lazy_static! {
         static ref RU: MorphAnalyzer = MorphAnalyzer::from_file(dict_ru::DICT_PATH);
+        static ref RU_STRICT: MorphAnalyzer = MorphAnalyzer {
+            pipeline: Pipeline::no_guessing(),
+            ..RU.clone()
+        };
     }
 
     #[test]
@@ -119,6 +361,306 @@ mod tests {
         let _ = RU.dict;
     }
 
+    #[test]
+    fn load_missing_dict() {
+        let err = MorphAnalyzer::try_from_file("/nonexistent/dict").unwrap_err();
+        match err {
+            DictionaryError::Io { ref file, .. } => {
+                assert_eq!(file, Path::new("/nonexistent/dict/meta.json.gz"))
+            }
+            _ => panic!("Unexpected error: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn load_broken_dict() {
+        // Loads the dictionary with the file replaced by the `data`.
+        let load = |name: &str, data: &[u8]| {
+            let dir = env::temp_dir().join(format!("rsmorphy-test-{}-broken", process::id()));
+            fs::create_dir_all(&dir).unwrap();
+            for entry in fs::read_dir(dict_ru::DICT_PATH).unwrap() {
+                let path = entry.unwrap().path();
+                fs::copy(&path, dir.join(path.file_name().unwrap())).unwrap();
+            }
+            fs::write(dir.join(name), data).unwrap();
+            let err = MorphAnalyzer::try_from_file(&dir).unwrap_err();
+            fs::remove_dir_all(&dir).unwrap();
+            assert_eq!(err.file(), &dir.join(name));
+            err
+        };
+        let gzip = |data: &[u8]| {
+            let mut file = GzEncoder::new(Vec::new(), Compression::fast());
+            file.write_all(data).unwrap();
+            file.finish().unwrap()
+        };
+
+        match load("suffixes.json.gz", b"[\"not gzipped\"]") {
+            DictionaryError::Gzip { .. } => {}
+            err => panic!("Unexpected error: {:?}", err),
+        }
+        match load("suffixes.json.gz", &gzip(b"[\"unclosed\"")) {
+            DictionaryError::Json { .. } => {}
+            err => panic!("Unexpected error: {:?}", err),
+        }
+        match load("meta.json.gz", &gzip(b"[[\"language_code\", \"ru\"]]")) {
+            DictionaryError::JsonShape { reason, .. } => {
+                assert_eq!(reason, "no `compile_options` object")
+            }
+            err => panic!("Unexpected error: {:?}", err),
+        }
+
+        let words = fs::File::open(Path::new(dict_ru::DICT_PATH).join("words.dawg.gz")).unwrap();
+        let mut head = vec![0; 100];
+        GzDecoder::new(words).read_exact(&mut head).unwrap();
+        match load("words.dawg.gz", &gzip(&head)) {
+            DictionaryError::TruncatedDawg { .. } => {}
+            err => panic!("Unexpected error: {:?}", err),
+        }
+
+        // One paradigm of two numbers, while entries are triples.
+        let paradigms: Vec<u8> = [1u16, 2, 0, 0]
+            .iter()
+            .flat_map(|n| n.to_le_bytes())
+            .collect();
+        match load("paradigms.array.gz", &gzip(&paradigms)) {
+            DictionaryError::ParadigmLength { paradigm, len, .. } => {
+                assert_eq!((paradigm, len), (0, 2))
+            }
+            err => panic!("Unexpected error: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn load_bundle() {
+        let path = env::temp_dir().join(format!("rsmorphy-test-{}.bundle", process::id()));
+        bundle::compile(dict_ru::DICT_PATH, &path).unwrap();
+        let morph = MorphAnalyzer::try_from_bundle(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert!(morph.dict.paradigms.entries.is_mapped());
+        assert_eq!(morph.dict.paradigms.len(), RU.dict.paradigms.len());
+        let gramtab = &morph.dict.gramtab;
+        assert!(gramtab.int_strings().bytes.is_mapped() && gramtab.ext_strings().bytes.is_mapped());
+        assert_eq!(gramtab.len(), RU.dict.gramtab.len());
+        for (tag, expected) in gramtab.iter().zip(RU.dict.gramtab.iter()) {
+            assert_eq!(tag.string, expected.string);
+            assert_eq!(tag.ext_string, expected.ext_string);
+            assert_eq!(tag.bits, expected.bits);
+        }
+        for word in &["минимальный", "менимальный", "стали", "по-западному"]
+        {
+            let parsed = |morph: &MorphAnalyzer| -> Vec<String> {
+                let parsed = morph.parse(word);
+                parsed.iter().map(|p| p.lex.stack.encoded()).collect()
+            };
+            assert_eq!(parsed(&morph), parsed(&RU));
+        }
+
+        // Errors name the broken section.
+        let mut writer = bundle::BundleWriter::new();
+        writer.add("meta.json", b"[".to_vec());
+        let mut file = fs::File::create(&path).unwrap();
+        writer.write_to(&mut file).unwrap();
+        let err = MorphAnalyzer::try_from_bundle(&path).unwrap_err();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(err.file(), &path.join("meta.json"));
+    }
+
+    #[test]
+    fn load_char_substitutes() {
+        let dir = env::temp_dir().join(format!("rsmorphy-test-{}-dict", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for entry in fs::read_dir(dict_ru::DICT_PATH).unwrap() {
+            let path = entry.unwrap().path();
+            let name = path.file_name().unwrap();
+            if name != "meta.json.gz" {
+                fs::copy(&path, dir.join(name)).unwrap();
+            }
+        }
+        let meta = fs::File::open(Path::new(dict_ru::DICT_PATH).join("meta.json.gz")).unwrap();
+        let mut meta: Vec<Value> = serde_json::from_reader(GzDecoder::new(meta)).unwrap();
+        meta.push(json!(["char_substitutes", {"0": "о"}]));
+        let file = fs::File::create(dir.join("meta.json.gz")).unwrap();
+        let mut file = GzEncoder::new(file, Compression::fast());
+        serde_json::to_writer(&mut file, &meta).unwrap();
+        file.finish().unwrap();
+
+        let morph = MorphAnalyzer::try_from_file(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+        let morph = morph.unwrap();
+        assert_eq!(
+            morph.dict.char_substitutes,
+            btreemap! {"0".into() => "о".into()}
+        );
+        assert!(morph.word_is_known("м0л0к0", false));
+        assert!(!morph.word_is_known("еще", false));
+    }
+
+    #[test]
+    fn char_substitutes() {
+        let parse = |morph: &MorphAnalyzer, word| morph.parse(word)[0].lex.get_word().into_owned();
+        let with = |char_substitutes| {
+            MorphAnalyzer::builder()
+                .char_substitutes(char_substitutes)
+                .build(RU.dict.clone())
+        };
+
+        assert_eq!(parse(&RU, "еще"), "ещё");
+        let strict = with(CharSubstitutes::Strict);
+        assert!(!strict.word_is_known("еще", false));
+        assert_eq!(parse(&strict, "ещё"), "ещё");
+
+        let ee = with(CharSubstitutes::Ee);
+        assert_eq!(parse(&ee, "еще"), "ещё");
+        assert_eq!(parse(&ee, "тёлефон"), "телефон");
+
+        let ocr = with(CharSubstitutes::map(vec![('0', 'о'), ('е', 'ё')]));
+        assert_eq!(parse(&ocr, "м0л0к0"), "молоко");
+        assert_eq!(parse(&ocr, "еще"), "ещё");
+        assert!(ocr.word_is_known("м0л0к0", true));
+        assert!(!ocr.word_is_known("еще", true));
+    }
+
+    #[test]
+    fn shared_between_threads() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<MorphAnalyzer>();
+
+        let morph = RU.clone();
+        assert!(Arc::ptr_eq(&morph.dict, &RU.dict));
+        let handle = thread::spawn(move || morph.parse("стали").len());
+        assert_eq!(handle.join().unwrap(), RU.parse("стали").len());
+    }
+
+    #[test]
+    fn parse_batch() {
+        let morph = MorphAnalyzer {
+            threads: 4,
+            ..RU.clone()
+        };
+        let words: Vec<String> = ["стали", "минимальный", "42", "менимальный", "с", "м."]
+            .iter()
+            .cycle()
+            .enumerate()
+            .map(|(i, word)| format!("{}{}", word, "и".repeat(i % 3)))
+            .take(1000)
+            .collect();
+        let encoded = |result: &ParseResult| -> Vec<String> {
+            result.iter().map(|p| p.lex.stack.encoded()).collect()
+        };
+
+        let batch = morph.parse_batch(&words);
+        assert_eq!(batch.len(), words.len());
+        for (word, result) in words.iter().zip(&batch) {
+            assert_eq!(encoded(result), encoded(&RU.parse(word)));
+        }
+
+        let lemmas = morph.lemmatize_batch(&["стали", "яблоками", "42"]);
+        assert_eq!(lemmas, vec!["стать", "яблоко", "42"]);
+    }
+
+    #[test]
+    fn parse_cached() {
+        let morph = MorphAnalyzer::builder().cache(2).build(RU.dict.clone());
+        let encoded = |result: ParseResult| -> Vec<String> {
+            result.iter().map(|p| p.lex.stack.encoded()).collect()
+        };
+
+        for word in &["стали", "Стали", "стали", "42", "стали", "Стали"] {
+            assert_eq!(
+                encoded(morph.parse(word)),
+                encoded(morph.parse_uncached(word))
+            );
+        }
+        let stats = morph.cache_stats().unwrap();
+        // The second "Стали" misses: it's evicted by "42" as the least recently used.
+        assert_eq!((stats.hits, stats.misses), (2, 4));
+        assert_eq!((stats.len, stats.capacity), (2, 2));
+
+        let clone = morph.clone();
+        clone.parse("стали");
+        assert_eq!(morph.cache_stats().unwrap().hits, 3);
+        assert_eq!(RU.cache_stats(), None);
+
+        // An analyzer with another pipeline doesn't take the results of the original.
+        let strict = MorphAnalyzer::builder()
+            .pipeline(Pipeline::no_guessing())
+            .shared_cache(morph.cache.clone().unwrap())
+            .build(RU.dict.clone());
+        assert_eq!(morph.parse("менимальный").len(), 3);
+        assert_eq!(strict.parse("менимальный").len(), 1);
+        assert_eq!(morph.parse("менимальный").len(), 3);
+        assert_eq!(strict.parse("менимальный").len(), 1);
+        assert_eq!(morph.cache_stats().unwrap().hits, 5);
+
+        // Entries of analyzers whose dictionary is gone are dropped.
+        let cache = Arc::new(ParseCache::new(10));
+        let other = MorphAnalyzer::builder()
+            .shared_cache(cache.clone())
+            .build((*RU.dict).clone());
+        other.parse("стали");
+        other.parse("42");
+        assert_eq!(cache.stats().len, 2);
+        drop(other);
+        let morph = MorphAnalyzer::builder()
+            .shared_cache(cache.clone())
+            .build(RU.dict.clone());
+        morph.parse("стали");
+        assert_eq!(cache.stats().len, 1);
+        assert_eq!(cache.stats().misses, 3);
+    }
+
+    #[test]
+    fn tag() {
+        let words = ["стали", "с", "минимальный", "менимальный", "по-западному"];
+        let shapes = ["42", "3.14", ",", "XIII", "pdf"];
+        for word in words.iter().chain(&shapes) {
+            let mut expected: Vec<&str> = Vec::new();
+            for parsed in RU.parse(word) {
+                let tag = parsed.lex.get_tag(&RU).string.as_str();
+                if !expected.contains(&tag) {
+                    expected.push(tag);
+                }
+            }
+            let tags: Vec<&str> = RU.tag(word).iter().map(|tag| tag.string.as_str()).collect();
+            assert_eq!(tags, expected, "{}", word);
+        }
+    }
+
+    #[test]
+    fn normal_forms() {
+        assert_eq!(RU.normal_forms("стали"), vec!["стать", "сталь"]);
+        assert_eq!(RU.normal_forms("яблоками"), vec!["яблоко"]);
+    }
+
+    #[test]
+    fn word_is_known() {
+        assert!(RU.word_is_known("Ёжик", true));
+        assert!(RU.word_is_known("ежик", false));
+        assert!(!RU.word_is_known("ежик", true));
+        assert!(!RU.word_is_known("менимальный", false));
+    }
+
+    #[test]
+    fn complete() {
+        let completions = RU.complete("Стал", 1000);
+        assert_eq!(completions.first().unwrap().0, "стал");
+        assert!(completions.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(completions.iter().all(|(word, _)| word.starts_with("стал")));
+        let stali = completions
+            .iter()
+            .find(|(word, _)| word == "стали")
+            .unwrap();
+        assert_eq!(stali.1.len(), 2);
+        assert!(stali.1.contains(&"стать".to_owned()));
+        assert!(stali.1.contains(&"сталь".to_owned()));
+
+        assert_eq!(RU.complete("стал", 3).len(), 3);
+        assert!(RU.complete("qwerty", 3).is_empty());
+        let keys: Vec<String> = RU.dict.words.keys_with_prefix("ёжик").collect();
+        assert!(keys.contains(&"ёжик".to_owned()) && keys.contains(&"ёжиками".to_owned()));
+    }
+
     #[test]
     fn parse() {
         assert_eq!(RU.parse("минимальный").len(), 2);
@@ -130,6 +672,18 @@ mod tests {
         assert_eq!(RU.parse("с").len(), 25);
     }
 
+    #[test]
+    fn parse_one_capital_letter() {
+        let parsed = RU.parse("С");
+        assert_eq!(parsed.len(), 49);
+        let initials = parsed
+            .iter()
+            .filter(|parsed| parsed.lex.get_tag(&RU).has(Flag::Init))
+            .count();
+        assert_eq!(initials, 24);
+        assert_eq!(RU.parse("Я").len(), 25);
+    }
+
     #[test]
     fn parse_one_letter_and_dot() {
         assert_eq!(RU.parse("м.").len(), 1);
@@ -147,4 +701,512 @@ mod tests {
         assert_eq!(RU.parse("Р-ка").len(), 1);
         assert_eq!(RU.parse("з-то").len(), 1);
     }
+
+    #[test]
+    fn parse_hyphenated() {
+        let parsed = RU.parse("человек-гора");
+        let lex = &parsed[0].lex;
+        assert_eq!(lex.encoded(), "ru:hw:d:человек,1e8;-d:гора,37");
+        assert_eq!(lex.get_tag(&RU).string, "NOUN,anim,masc sing,nomn");
+        assert_eq!(lex.get_normal_form(&RU), "человек-гора");
+        // Scored by the left part only.
+        assert!(parsed[0].score > parsed[1].score);
+        assert_eq!(&Lex::from_id(&RU, lex.encoded()).unwrap(), lex);
+        let gent = lex.inflect(&RU, &GrammemeSet::new("gent")).unwrap();
+        assert_eq!(gent.get_word(), "человека-горы");
+
+        // The left part is paired with both lexemes of "лама".
+        let encoded = RU
+            .parse("человек-лама")
+            .into_iter()
+            .map(|parsed| parsed.lex.encoded())
+            .collect::<Vec<_>>();
+        assert!(encoded.contains(&"ru:hw:d:человек,1e8;-d:лама,35".to_owned()));
+        assert!(encoded.contains(&"ru:hw:d:человек,1e8;-d:лама,201".to_owned()));
+
+        let parsed = RU.parse("школа-интернат");
+        let lex = &parsed[0].lex;
+        assert_eq!(lex.get_tag(&RU).string, "NOUN,inan,femn sing,nomn");
+        let gent = lex.inflect(&RU, &GrammemeSet::new("gent")).unwrap();
+        assert_eq!(gent.get_word(), "школы-интерната");
+
+        // Only the right part is inflected.
+        let lex = RU
+            .parse("сайт-визитка")
+            .into_iter()
+            .map(|parsed| parsed.lex)
+            .find(|lex| lex.stack.stack.is_fixed_left())
+            .unwrap();
+        assert_eq!(lex.encoded(), "ru:hw:u:сайт;-d:визитка,8");
+        assert_eq!(&Lex::from_id(&RU, lex.encoded()).unwrap(), &lex);
+        let gent = lex.inflect(&RU, &GrammemeSet::new("gent")).unwrap();
+        assert_eq!(gent.get_word(), "сайт-визитки");
+
+        assert_eq!(
+            RU.parse("интернет-магазин")[0].lex.encoded(),
+            "ru:d:интернет-магазин,21"
+        );
+        assert_eq!(
+            RU.parse("экс-чемпион")[0].lex.encoded(),
+            "ru:d:чемпион,34;kp:экс-"
+        );
+    }
+
+    #[test]
+    fn parse_homoglyphs() {
+        // "задaч" with a Latin "a"
+        let parsed = RU.parse("Задaч");
+        assert_eq!(parsed.len(), 1);
+        let lex = &parsed[0].lex;
+        assert_eq!(lex.encoded(), "ru:r:Задaч,d:задач,5d,8");
+        assert_eq!(lex.get_word(), "задач");
+        assert_eq!(lex.get_normal_form(&RU), "задача");
+        let repaired = lex.stack.stack.left.stack.as_repaired().unwrap();
+        assert_eq!(repaired.original, "Задaч");
+        assert_eq!(&Lex::from_id(&RU, lex.encoded()).unwrap(), lex);
+
+        let normal_forms = RU.normal_forms("мoжeт");
+        assert!(normal_forms.contains(&"мочь".to_owned()));
+
+        // Not mixed, or no look-alike for "z".
+        assert_eq!(RU.parse("pdf")[0].lex.get_tag(&RU).string, "LATN");
+        let lex = &RU.parse("zадач")[0].lex;
+        assert!(lex.stack.stack.left.stack.as_repaired().is_none());
+    }
+
+    #[test]
+    fn lex_language() {
+        let lex = &RU.parse("яблоко")[0].lex;
+        assert_eq!(lex.lang, Language::Russian);
+        assert!(lex.encoded().starts_with("ru:"));
+
+        let id = lex.encoded().replacen("ru:", "uk:", 1);
+        let (_, lex) = Lex::decode(&id).unwrap();
+        assert_eq!(lex.lang, Language::Ukrainian);
+        assert_eq!(lex.encoded(), id);
+        assert_eq!(
+            Lex::from_id(&RU, &id),
+            Err(DecodeError::WrongLanguage(Language::Ukrainian))
+        );
+        assert_eq!(
+            Lex::from_id(&RU, "xx:d:яблоко,1"),
+            Err(DecodeError::UnknownPartType)
+        );
+    }
+
+    #[test]
+    fn numeral_agreement() {
+        let tag = OpencorporaTagReg::new("NOUN,inan,masc sing,nomn");
+        let agree = |language, num| tag.numeral_agreement_grammemes_in(language, num);
+        let russian = tag.numeral_agreement_grammemes(2);
+        assert_eq!(russian, agree(Language::Russian, 2));
+        assert_eq!(agree(Language::Russian, 2), GrammemeSet::new("sing,gent"));
+        assert_eq!(agree(Language::Russian, 5), GrammemeSet::new("plur,gent"));
+        assert_eq!(agree(Language::Ukrainian, 2), GrammemeSet::new("plur,nomn"));
+        assert_eq!(agree(Language::Ukrainian, 1), GrammemeSet::new("sing,nomn"));
+        assert_eq!(agree(Language::Ukrainian, 5), GrammemeSet::new("plur,gent"));
+    }
+
+    #[test]
+    fn tag_ext() {
+        let morph = MorphAnalyzer::builder()
+            .tagset(Tagset::External)
+            .build(RU.dict.clone());
+        let tags: Vec<_> = morph
+            .tag("ёжик")
+            .into_iter()
+            .map(|tag| morph.tag_string(tag))
+            .collect();
+        assert_eq!(tags, vec!["СУЩ,од,мр ед,им"]);
+        let tag = morph.tag("42")[0];
+        assert_eq!(tag.ext_string, None);
+        assert_eq!(morph.tag_string(tag), "ЧИСЛО,цел");
+        assert_eq!(RU.tag_string(tag), "NUMB,intg");
+        let tag = OpencorporaTagReg::new("NOUN,anim,masc,Sgtm,Fixd,Abbr,Init sing,nomn");
+        assert_eq!(morph.tag_string(&tag), "СУЩ,од,мр,sg,0,аббр,иниц ед,им");
+    }
+
+    #[test]
+    fn tag_flags() {
+        // Grammemes out of categories are all flags.
+        for (grammeme, reg) in &RU.dict.grammemes {
+            if reg.parent.is_none() && RU.dict.grammeme_metas[grammeme].children.is_empty() {
+                assert!(Flag::ALL
+                    .iter()
+                    .any(|&flag| Grammeme::from(flag) == *grammeme));
+            }
+        }
+        let tag = OpencorporaTagReg::new("NOUN,anim,femn,Sgtm,Surn sing,nomn");
+        assert_eq!(
+            tag.flags.iter().collect::<Vec<_>>(),
+            vec![Flag::Sgtm, Flag::Surn]
+        );
+        assert!(tag.flags.intersects(FlagGroup::Proper));
+        assert!(RU.tag("иванова").iter().any(|tag| tag.has(Flag::Surn)));
+    }
+
+    #[test]
+    fn tag_exact_categories() {
+        use crate::opencorpora::grammeme::{GrammemeReg, GrammemeTable};
+        use crate::opencorpora::kind::{Case, PartOfSpeach};
+
+        let tag = OpencorporaTagReg::new("COMP,Cmp2,V-ej");
+        assert_eq!(tag.pos, Some(PartOfSpeach::Comp));
+        assert_eq!(tag.case, None);
+        assert_eq!(
+            tag.flags.iter().collect::<Vec<_>>(),
+            vec![Flag::Cmp2, Flag::VEj]
+        );
+
+        let mut grammemes = RU.dict.grammemes.clone();
+        grammemes.insert(
+            Grammeme::new("loc3"),
+            GrammemeReg {
+                name: Grammeme::new("loc3"),
+                parent: Some(Grammeme::new("loct")),
+                ..RU.dict.grammemes[&Grammeme::new("loc2")].clone()
+            },
+        );
+        let table = GrammemeTable::new(&grammemes);
+        let tag = OpencorporaTagReg::with_table("NOUN,inan,masc sing,loc3", &table);
+        assert_eq!(tag.case, Some(Case::Loct));
+        assert_eq!(OpencorporaTagReg::new("NOUN sing,loc3").case, None);
+
+        // The dictionary table agrees with the grammemes known to rsmorphy.
+        for tag in RU.dict.gramtab.iter() {
+            let new = OpencorporaTagReg::new(tag.string.as_str());
+            assert_eq!(
+                (tag.pos, tag.case, tag.number),
+                (new.pos, new.case, new.number)
+            );
+            assert_eq!(
+                (tag.gender, tag.person, tag.tense),
+                (new.gender, new.person, new.tense)
+            );
+            assert_eq!(tag.flags, new.flags);
+        }
+    }
+
+    #[test]
+    fn grammeme_bits() {
+        let dict = &RU.dict;
+        assert_eq!(dict.grammeme_metas[&Grammeme::new("POST")].index, 0);
+        assert_eq!(dict.grammeme_metas[&Grammeme::new("NOUN")].index, 1);
+        for tag in dict.gramtab.iter() {
+            let bits = tag.bits.unwrap();
+            assert_eq!(dict.grammeme_set(bits), tag.grammemes);
+            assert_eq!(
+                dict.grammeme_bits(&GrammemeSet::new(dict.grammemes_string(bits))),
+                Some(bits)
+            );
+        }
+        let tag = OpencorporaTagReg::new("NUMB,intg");
+        assert_eq!(dict.tag_bits(&tag).map(|bits| bits.len()), Some(2));
+
+        let required = GrammemeSet::new("plur,ablt");
+        for parsed in RU.parse("стали") {
+            let tag = parsed.lex.get_tag(&RU);
+            let bits = tag
+                .prepare_required_bits(&RU, dict.grammeme_bits(&required).unwrap())
+                .unwrap();
+            assert_eq!(
+                dict.grammeme_set(bits),
+                tag.prepare_required(&RU, &required)
+            );
+        }
+    }
+
+    #[test]
+    fn parse_filtered() {
+        let tags = |query| {
+            let parsed = RU.parse_filtered("стали", query).unwrap();
+            parsed
+                .iter()
+                .map(|parsed| parsed.lex.get_tag(&RU).string.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            tags("NOUN & (gent | datv) & !plur"),
+            vec!["NOUN,inan,femn sing,gent", "NOUN,inan,femn sing,datv"]
+        );
+        assert!(tags("LATN | PNCT").is_empty());
+
+        assert_eq!(
+            RU.parse_filtered("стали", "NOUN & nomm"),
+            Err(TagQueryError::UnknownGrammeme("nomm".to_owned()))
+        );
+        assert_eq!(
+            RU.parse_filtered("стали", "NOUN & (nomn | "),
+            Err(TagQueryError::UnexpectedEnd)
+        );
+
+        let query = "NOUN & (gent | datv) & !plur";
+        assert_eq!(
+            RU.parse_matching("стали", &TagQuery::parse(query).unwrap()),
+            RU.parse_filtered("стали", query).unwrap()
+        );
+    }
+
+    #[test]
+    fn lex_view() {
+        let parsed = &RU.parse("стали")[0];
+        let view = parsed.view(&RU);
+        assert_eq!(
+            serde_json::to_value(&view).unwrap(),
+            json!({
+                "word": "стали",
+                "normal_form": "стать",
+                "tag": "VERB,perf,intr plur,past,indc",
+                "score": parsed.score.value(),
+                "is_known": true,
+                "id": parsed.lex.encoded(),
+            })
+        );
+        assert_eq!(Lex::from_id(&RU, &view.id).unwrap(), parsed.lex);
+        let lemma = parsed.lex.get_lemma(&RU).view(&RU);
+        assert_eq!((lemma.word.as_str(), lemma.score), ("стать", None));
+        assert!(!serde_json::to_string(&lemma).unwrap().contains("score"));
+
+        let tag: OpencorporaTagReg = view.tag.parse().unwrap();
+        assert_eq!(tag.to_string(), view.tag);
+        assert_eq!(tag.grammemes.to_string().parse(), Ok(tag.grammemes));
+        assert_eq!(Grammeme::new("perf").to_string(), "perf");
+        assert!("VERB,perf intr".parse::<OpencorporaTagReg>().is_ok());
+        assert!("VERB,,perf".parse::<OpencorporaTagReg>().is_err());
+        assert_eq!("".parse(), Ok(GrammemeSet::default()));
+    }
+
+    #[test]
+    fn to_pymorphy2() {
+        let parse = |word| -> Vec<Value> {
+            RU.parse(word)
+                .iter()
+                .map(|parsed| serde_json::to_value(parsed.to_pymorphy2(&RU)).unwrap())
+                .collect()
+        };
+        assert_eq!(
+            parse("псевдокошка")[0],
+            json!([
+                "псевдокошка",
+                "NOUN,anim,femn sing,nomn",
+                "псевдокошка",
+                RU.parse("псевдокошка")[0].score.value(),
+                [
+                    ["DictionaryAnalyzer", "кошка", 132, 0],
+                    ["KnownPrefixAnalyzer", "псевдо"]
+                ]
+            ])
+        );
+        assert_eq!(
+            parse("кошка-то")[0][4],
+            json!([
+                ["DictionaryAnalyzer", "кошка", 132, 0],
+                ["HyphenSeparatedParticleAnalyzer", "-то"]
+            ])
+        );
+        assert_eq!(
+            parse("менимальный")[0][4],
+            json!([
+                ["FakeDictionary", "менимальный", 10, 0],
+                ["KnownSuffixAnalyzer", "льный"]
+            ])
+        );
+        assert_eq!(parse("1984")[0][4], json!([["NumberAnalyzer", "1984"]]));
+        assert_eq!(
+            parse("задaч")[0][4],
+            json!([[
+                "HomoglyphAnalyzer",
+                "задaч",
+                [["DictionaryAnalyzer", "задач", 93, 8]]
+            ]])
+        );
+
+        let parsed: Pymorphy2Parse = serde_json::from_value(parse("стали")[0].clone()).unwrap();
+        assert_eq!(parsed, RU.parse("стали")[0].to_pymorphy2(&RU));
+    }
+
+    #[test]
+    fn inflect_strict() {
+        let lex = &RU.parse("ножницы")[0].lex;
+        assert_eq!(lex.get_tag(&RU).string, "NOUN,inan,GNdr,Pltm plur,nomn");
+        let required = GrammemeSet::new("sing,ablt");
+        // The lenient one gives a form anyway.
+        assert_eq!(lex.inflect(&RU, &required).unwrap().get_word(), "ножницами");
+        match lex.inflect_strict(&RU, &required) {
+            Err(InflectError::Unsatisfied { missing, closest }) => {
+                assert_eq!(missing, vec![Grammeme::new("sing")]);
+                assert_eq!(closest.get_word(), "ножницами");
+            }
+            result => panic!("{:?}", result),
+        }
+        assert_eq!(
+            lex.inflect_strict(&RU, &GrammemeSet::new("plur,ablt"))
+                .unwrap()
+                .get_word(),
+            "ножницами"
+        );
+        assert_eq!(
+            lex.inflect_strict(&RU, &GrammemeSet::new("ablt,abcd")),
+            Err(InflectError::UnknownGrammeme(Grammeme::new("abcd")))
+        );
+
+        let lex = &RU.parse("кошка")[0].lex;
+        let gent = lex
+            .inflect_strict(&RU, &GrammemeSet::new("plur,gent"))
+            .unwrap();
+        assert_eq!(gent.get_word(), "кошек");
+        let err = lex
+            .inflect_strict(&RU, &GrammemeSet::new("PRTF,voct"))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "no form of the lexeme has PRTF,voct");
+    }
+
+    #[test]
+    fn inflect_fallbacks() {
+        let inflect = |word, required| {
+            let inflected = RU.parse(word)[0]
+                .lex
+                .inflect_with_fallbacks(&RU, &GrammemeSet::new(required))
+                .unwrap();
+            let fallbacks: Vec<String> = inflected
+                .fallbacks
+                .iter()
+                .map(|fallback| format!("{}>{}", fallback.required, fallback.used))
+                .collect();
+            format!("{} {}", inflected.lex.get_word(), fallbacks.join(","))
+        };
+        assert_eq!(inflect("лес", "loc2"), "лесу ");
+        assert_eq!(inflect("книга", "loc2"), "книге loc2>loct");
+        assert_eq!(inflect("снег", "gen2"), "снегу ");
+        assert_eq!(inflect("кошка", "gen2,plur"), "кошек gen2>gent");
+        assert_eq!(inflect("кошка", "gen1"), "кошки gen1>gent");
+        assert_eq!(inflect("стол", "ablt"), "столом ");
+        assert_eq!(inflect("лес", "plur,loc2"), "лесах loc2>loct");
+
+        let lex = &RU.parse("книга")[0].lex;
+        let loct = lex.inflect(&RU, &GrammemeSet::new("loc2")).unwrap();
+        assert_eq!(loct.get_tag(&RU).string, "NOUN,inan,femn sing,loct");
+    }
+
+    #[test]
+    fn plural_of_incomplete_lexemes() {
+        let plural = |word, num| {
+            let lex = &RU.parse(word)[0].lex;
+            lex.get_plural(&RU, num).unwrap().get_word().into_owned()
+        };
+        // No form has all the required grammemes, the closest one is taken.
+        assert_eq!(plural("ножницы", 1), "ножницы");
+        assert_eq!(plural("ножницы", 2), "ножниц");
+        assert_eq!(plural("ножницы", 21), "ножницы");
+        assert_eq!(plural("молоко", 5), "молока");
+        assert_eq!(plural("яблоко", 5), "яблок");
+    }
+
+    #[test]
+    fn inflect_variants() {
+        let inflect = |morph: &MorphAnalyzer, word, required| {
+            let lex = &morph.parse(word)[0].lex;
+            let required = GrammemeSet::new(required);
+            lex.inflect(morph, &required)
+                .unwrap()
+                .get_word()
+                .into_owned()
+        };
+        assert_eq!(inflect(&RU, "рыба", "ablt"), "рыбой");
+        assert_eq!(inflect(&RU, "рыбою", "plur"), "рыбами");
+        assert_eq!(inflect(&RU, "рыба", "ablt,V-oy"), "рыбою");
+        assert_eq!(inflect(&RU, "кошка", "ablt"), "кошкой");
+        // An avoided form is still taken over ones without the required case.
+        assert_eq!(inflect(&RU, "стол", "loc2"), "столу");
+
+        let policy = VariantPolicy::default().prefer(Flag::VOy);
+        assert!(policy.is_preferred(Flag::VOy));
+        assert!(policy.is_avoided(Flag::VEy) && policy.is_avoided(Flag::Arch));
+        assert!(!policy.is_avoided(Flag::Slng));
+        let morph = MorphAnalyzer {
+            variant_policy: policy,
+            ..RU.clone()
+        };
+        assert_eq!(inflect(&morph, "рыба", "ablt"), "рыбою");
+        assert_eq!(inflect(&morph, "рыба", "datv"), "рыбе");
+
+        let morph = MorphAnalyzer {
+            variant_policy: VariantPolicy::default().avoid(Flag::Supr),
+            ..RU.clone()
+        };
+        assert_eq!(inflect(&morph, "красивая", "ablt"), "красивой");
+    }
+
+    #[test]
+    fn parse_no_guessing() {
+        assert_eq!(RU_STRICT.parse("минимальный").len(), 2);
+        let parsed = RU_STRICT.parse("менимальный");
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].lex.get_tag(&RU_STRICT).string, "UNKN");
+    }
+
+    #[test]
+    fn parse_reordered() {
+        let mut pipeline = Pipeline::default();
+        pipeline.set_stop(UnitKind::Number, false);
+        pipeline.reorder(&[UnitKind::Number, UnitKind::Unknown]);
+        let morph = MorphAnalyzer {
+            pipeline,
+            ..RU.clone()
+        };
+        assert_eq!(RU.parse("42").len(), 1);
+        assert_eq!(morph.parse("42").len(), 2);
+        assert_eq!(morph.parse("слово").len(), 1);
+    }
+
+    #[derive(Debug)]
+    struct SkuAnalyzer {
+        tags: Vec<OpencorporaTagReg>,
+    }
+
+    impl AnalyzerUnit for SkuAnalyzer {
+        fn parse(
+            &self,
+            morph: &MorphAnalyzer,
+            result: &mut ParseResult,
+            _word: &str,
+            word_lower: &str,
+            _seen_parses: &mut SeenSet,
+        ) {
+            if word_lower.starts_with("sku-") {
+                let container = Custom::new("sku", WordStruct::known(word_lower), 0);
+                let lex = Lex::from_stack(morph, StackSource::from(container));
+                result.push(Parsed::new(lex, Score::Fake(1.0)));
+            }
+        }
+
+        fn tags(&self) -> &[OpencorporaTagReg] {
+            &self.tags
+        }
+    }
+
+    #[test]
+    fn parse_custom_unit() {
+        let sku = SkuAnalyzer {
+            tags: vec![OpencorporaTagReg::new("NOUN,inan,masc,Fixd sing,nomn")],
+        };
+        let morph = MorphAnalyzer::builder()
+            .register("sku", sku)
+            .with_before(UnitKind::Custom("sku"), UnitKind::Latin, true)
+            .build(RU.dict.clone());
+
+        let parsed = morph.parse("SKU-42");
+        assert_eq!(parsed.len(), 1);
+        let lex = &parsed[0].lex;
+        assert_eq!(lex.get_tag(&morph).string, "NOUN,inan,masc,Fixd sing,nomn");
+        assert_eq!(lex.encoded(), "ru:c:sku,0,d,sku-42");
+        assert_eq!(&Lex::from_id(&morph, lex.encoded()).unwrap(), lex);
+
+        // Without the registered unit the pipeline step is skipped.
+        let morph = MorphAnalyzer {
+            pipeline: morph.pipeline.clone(),
+            ..RU.clone()
+        };
+        assert_eq!(morph.parse("SKU-42")[0].lex.get_tag(&morph).string, "LATN");
+    }
 }
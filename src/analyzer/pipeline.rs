@@ -0,0 +1,130 @@
+/// Names one of the analyzer units held by `Units`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitKind {
+    Dictionary,
+    Homoglyph,
+    Initials,
+    Number,
+    Punct,
+    Roman,
+    Latin,
+    Hsp,
+    Ha,
+    Hword,
+    Kp,
+    Up,
+    Ks,
+    Unknown,
+    /// A user-defined unit from `UnitRegistry`.
+    Custom(&'static str),
+}
+
+/// A single call of a unit within the pipeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub unit: UnitKind,
+    /// Stop the search when the result is not empty after this unit.
+    pub stop: bool,
+}
+
+impl Step {
+    pub fn new(unit: UnitKind, stop: bool) -> Self {
+        Step { unit, stop }
+    }
+}
+
+/// The ordered list of units `MorphAnalyzer::parse` looks over.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pipeline {
+    pub steps: Vec<Step>,
+}
+
+impl Default for Pipeline {
+    /// The full pipeline with prediction, the same as in PyMorphy2
+    /// plus the repair of Latin look-alike letters.
+    fn default() -> Self {
+        use self::UnitKind::*;
+
+        Pipeline::new(vec![
+            Step::new(Dictionary, false),
+            // Initials add to dictionary parses of capital letters, so they
+            // go before the repair, which stops the search.
+            Step::new(Initials, true),
+            Step::new(Homoglyph, true),
+            Step::new(Number, true),
+            Step::new(Punct, true),
+            Step::new(Roman, false),
+            Step::new(Latin, true),
+            Step::new(Hsp, true),
+            Step::new(Ha, true),
+            Step::new(Hword, true),
+            Step::new(Kp, true),
+            Step::new(Up, false),
+            Step::new(Ks, true),
+            Step::new(Unknown, true),
+        ])
+    }
+}
+
+impl Pipeline {
+    pub fn new(steps: Vec<Step>) -> Self {
+        Pipeline { steps }
+    }
+
+    /// The pipeline without guessers: words absent in the dictionary
+    /// are not predicted by prefixes or suffixes and come out as `UNKN`.
+    pub fn no_guessing() -> Self {
+        let mut pipeline = Pipeline::default();
+        pipeline.disable(UnitKind::Kp);
+        pipeline.disable(UnitKind::Up);
+        pipeline.disable(UnitKind::Ks);
+        pipeline
+    }
+
+    pub fn position(&self, unit: UnitKind) -> Option<usize> {
+        self.steps.iter().position(|step| step.unit == unit)
+    }
+
+    pub fn contains(&self, unit: UnitKind) -> bool {
+        self.position(unit).is_some()
+    }
+
+    /// Appends the `unit` to the end, or only updates its `stop` flag
+    /// if the unit is already in the pipeline.
+    pub fn enable(&mut self, unit: UnitKind, stop: bool) {
+        match self.position(unit) {
+            Some(pos) => self.steps[pos].stop = stop,
+            None => self.steps.push(Step::new(unit, stop)),
+        }
+    }
+
+    /// Inserts the `unit` right before `before` (or appends it if `before`
+    /// is not in the pipeline). The unit is moved if it is already there.
+    pub fn insert_before(&mut self, unit: UnitKind, before: UnitKind, stop: bool) {
+        self.disable(unit);
+        let pos = self.position(before).unwrap_or(self.steps.len());
+        self.steps.insert(pos, Step::new(unit, stop));
+    }
+
+    pub fn disable(&mut self, unit: UnitKind) {
+        self.steps.retain(|step| step.unit != unit);
+    }
+
+    /// Sets whether the search stops after the `unit`; does nothing
+    /// if the unit is not in the pipeline.
+    pub fn set_stop(&mut self, unit: UnitKind, stop: bool) {
+        if let Some(pos) = self.position(unit) {
+            self.steps[pos].stop = stop;
+        }
+    }
+
+    /// Rearranges units in the given order, keeping their `stop` flags.
+    /// Units which are not listed in `order` are removed.
+    pub fn reorder(&mut self, order: &[UnitKind]) {
+        let steps = order
+            .iter()
+            .filter_map(|&unit| self.position(unit).map(|pos| self.steps[pos]))
+            .collect();
+        self.steps = steps;
+    }
+}
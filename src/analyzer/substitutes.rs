@@ -0,0 +1,62 @@
+use std::collections::BTreeMap;
+
+use lazy_static::lazy_static;
+
+use crate::opencorpora::dictionary::Dictionary;
+
+lazy_static! {
+    static ref STRICT: BTreeMap<String, String> = BTreeMap::new();
+    static ref EE: BTreeMap<String, String> = maplit::btreemap! {
+        "е".into() => "ё".into(),
+        "ё".into() => "е".into(),
+    };
+}
+
+/// Letters of a word which may also stand for other letters when the word
+/// is looked up in the dictionary.
+///
+/// ```
+/// use rsmorphy::analyzer::{CharSubstitutes, MorphAnalyzerBuilder};
+///
+/// // Fix "0" misrecognized instead of "о".
+/// let substitutes = CharSubstitutes::map(vec![('0', 'о'), ('е', 'ё')]);
+/// let morph = MorphAnalyzerBuilder::new()
+///     .char_substitutes(substitutes)
+///     .build_from_file(rsmorphy::dict_ru::DICT_PATH);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum CharSubstitutes {
+    /// The substitutes of the dictionary, "е" for "ё" in Russian
+    #[default]
+    Dictionary,
+    /// No substitutes, "е" doesn't match "ё"
+    Strict,
+    /// "е" matches "ё" and vice versa
+    Ee,
+    /// User-supplied substitutes, the letter of a word → the letter
+    /// in the dictionary
+    Map(BTreeMap<String, String>),
+}
+
+impl CharSubstitutes {
+    pub fn map<I>(substitutes: I) -> Self
+    where
+        I: IntoIterator<Item = (char, char)>,
+    {
+        let substitutes = substitutes
+            .into_iter()
+            .map(|(from, to)| (from.to_string(), to.to_string()))
+            .collect();
+        CharSubstitutes::Map(substitutes)
+    }
+
+    /// The substitutes in the form `CompletionDawg::similar_items` takes.
+    pub fn get<'a>(&'a self, dict: &'a Dictionary) -> &'a BTreeMap<String, String> {
+        match self {
+            CharSubstitutes::Dictionary => &dict.char_substitutes,
+            CharSubstitutes::Strict => &STRICT,
+            CharSubstitutes::Ee => &EE,
+            CharSubstitutes::Map(substitutes) => substitutes,
+        }
+    }
+}
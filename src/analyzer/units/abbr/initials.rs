@@ -1,6 +1,4 @@
 use std::borrow::Cow;
-use std::collections::BTreeSet;
-use std::iter::FromIterator;
 
 use crate::analyzer::units::abc::AnalyzerUnit;
 use crate::analyzer::MorphAnalyzer;
@@ -8,14 +6,6 @@ use crate::container::stack::StackSource;
 use crate::container::{Initials, InitialsKind, Lex, ParseResult, Parsed, Score, SeenSet};
 use crate::opencorpora::OpencorporaTagReg;
 
-lazy_static::lazy_static! {
-    #[derive(Debug)]
-    pub static ref LETTERS: BTreeSet<&'static str> = {
-        let set = "АБВГДЕЁЖЗИЙКЛМНОПРСТУФХЦЧШЩЭЮЯ".split("").filter(|v| !v.is_empty());
-        BTreeSet::from_iter(set)
-    };
-}
-
 const SCORE: Score = Score::Fake(0.1);
 
 #[derive(Debug, Clone)]
@@ -63,13 +53,10 @@ impl AnalyzerUnit for InitialsAnalyzer {
     ) {
         log::trace!("AbbreviatedFirstNameAnalyzer::parse()");
         log::trace!(r#" word: "{}", word_lower: "{}" "#, word, word_lower);
-        log::trace!(
-            r#" LETTERS: "{:?}" "#,
-            LETTERS.iter().cloned().collect::<Vec<&str>>().join(", ")
-        );
-        log::trace!(r#" LETTERS contains word: "{}" "#, LETTERS.contains(word));
+        let letters = morph.dict.language.letters();
+        log::trace!(r#" letters: "{}" "#, letters);
 
-        if let Some(&letter) = LETTERS.get(word) {
+        if let Some(letter) = find_letter(letters, word) {
             for (tag_idx, &(_, kind)) in self.tags.iter().enumerate() {
                 let tag_idx = tag_idx as u8;
                 let letter = Cow::from(letter);
@@ -84,3 +71,11 @@ impl AnalyzerUnit for InitialsAnalyzer {
         }
     }
 }
+
+/// Finds the `word` consisting of a single letter among `letters`.
+fn find_letter(letters: &'static str, word: &str) -> Option<&'static str> {
+    letters
+        .char_indices()
+        .map(|(pos, ch)| &letters[pos..pos + ch.len_utf8()])
+        .find(|&letter| letter == word)
+}
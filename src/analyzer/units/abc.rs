@@ -1,5 +1,7 @@
 use crate::analyzer::MorphAnalyzer;
+use crate::container::abc::*;
 use crate::container::{ParseResult, SeenSet};
+use crate::opencorpora::OpencorporaTagReg;
 
 pub trait AnalyzerUnit {
     fn parse(
@@ -10,4 +12,24 @@ pub trait AnalyzerUnit {
         word_lower: &str,
         seen_parses: &mut SeenSet,
     );
+
+    /// Collects tags of the word the way `parse` does, but may skip building
+    /// `Lex`es; the default implementation takes the tags of `parse` results.
+    fn tag<'m>(
+        &self,
+        morph: &'m MorphAnalyzer,
+        result: &mut Vec<&'m OpencorporaTagReg>,
+        word: &str,
+        word_lower: &str,
+        seen_parses: &mut SeenSet,
+    ) {
+        let mut parses = ParseResult::new();
+        self.parse(morph, &mut parses, word, word_lower, seen_parses);
+        result.extend(parses.iter().map(|parsed| parsed.lex.get_tag(morph)));
+    }
+
+    /// Tags of `Custom` sources emitted by the unit, indexed by `Custom::tag_idx`.
+    fn tags(&self) -> &[OpencorporaTagReg] {
+        &[]
+    }
 }
@@ -6,7 +6,6 @@ use crate::container::{HyphenAdverb, Lex, ParseResult, Parsed, Score, SeenSet, W
 use crate::opencorpora::kind::{Case, Number, PartOfSpeach};
 use crate::opencorpora::OpencorporaTagReg;
 
-const HA_PREFIX: &str = "по-";
 const HA_SCORE: Score = Score::Fake(0.7);
 
 /// Detect adverbs that starts with "по-".
@@ -38,12 +37,16 @@ impl AnalyzerUnit for HyphenAdverbAnalyzer {
         log::trace!("HyphenAdverbAnalyzer::parse()");
         log::trace!(r#" word: "{}", word_lower: "{}" "#, word, word_lower);
 
-        if word.chars().count() < 5 || !word.starts_with(HA_PREFIX) {
+        let prefix = match morph.dict.language.hyphen_adverb_prefix() {
+            Some(prefix) => prefix,
+            None => return,
+        };
+        if word.chars().count() < 5 || !word.starts_with(prefix) {
             return;
         }
 
         morph
-            .parse(&word[HA_PREFIX.len()..])
+            .parse(&word[prefix.len()..])
             .into_iter()
             .filter(|parsed| {
                 let tag = parsed.lex.get_tag(morph);
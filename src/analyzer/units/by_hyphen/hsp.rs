@@ -3,10 +3,6 @@ use crate::analyzer::MorphAnalyzer;
 use crate::container::stack::StackParticle;
 use crate::container::{HyphenSeparatedParticle, Lex, ParseResult, Parsed, SeenSet};
 
-// TODO move into `Dictionary`
-pub static PARTICLES_AFTER_HYPHEN: [&str; 8] =
-    ["-то", "-ка", "-таки", "-де", "-тко", "-тка", "-с", "-ста"];
-
 pub const SCORE_DECAY: f64 = 0.9;
 
 /// Parse the word by analyzing it without
@@ -35,7 +31,7 @@ impl AnalyzerUnit for HyphenSeparatedParticleAnalyzer {
         log::trace!("HyphenSeparatedParticleAnalyzer::parse()");
         log::trace!(r#" word = "{}", word_lower = "{}" "#, word, word_lower);
 
-        for &particle in &PARTICLES_AFTER_HYPHEN {
+        for &particle in morph.dict.language.particles_after_hyphen() {
             if word_lower.len() <= particle.len() || !word_lower.ends_with(particle) {
                 continue;
             };
@@ -1,15 +1,10 @@
-use crate::{
-    analyzer::{units::abc::*, MorphAnalyzer},
-    container::{
-        ParseResult,
-        SeenSet,
-        //        Dictionary,
-        //        HyphenSeparatedParticle,
-        //        Lex,
-        //        Score,
-        //        stack::{Stack, StackAffix, StackHyphenated},
-    },
-};
+use crate::analyzer::units::abc::*;
+use crate::analyzer::MorphAnalyzer;
+use crate::container::abc::*;
+use crate::container::stack::hword::similarity_features;
+use crate::container::stack::{StackAffix, StackHyphenated, StackSource};
+use crate::container::{Lex, ParseResult, Parsed, SeenSet, Unknown};
+use crate::util::add_parsed_if_not_seen;
 
 /// Parse the word by parsing its hyphen-separated parts.
 ///
@@ -18,57 +13,129 @@ use crate::{
 /// * интернет-магазин -> "интернет-" + магазин
 /// * человек-гора -> человек + гора
 
-#[derive(Default, Debug, Clone, Copy)]
-pub struct HyphenatedWordsAnalyzer {}
+#[derive(Debug, Clone, Copy)]
+pub struct HyphenatedWordsAnalyzer {
+    pub estimate_decay: f64,
+    /// Skip words which start with a known prefix: such words should be
+    /// parsed by `KnownPrefixAnalyzer`.
+    pub skip_known_prefixes: bool,
+}
+
+impl Default for HyphenatedWordsAnalyzer {
+    fn default() -> Self {
+        HyphenatedWordsAnalyzer {
+            estimate_decay: 0.75,
+            skip_known_prefixes: true,
+        }
+    }
+}
 
 impl AnalyzerUnit for HyphenatedWordsAnalyzer {
     fn parse(
         &self,
-        _morph: &MorphAnalyzer,
-        _result: &mut ParseResult,
+        morph: &MorphAnalyzer,
+        result: &mut ParseResult,
         word: &str,
         word_lower: &str,
-        _seen_parses: &mut SeenSet,
+        seen_parses: &mut SeenSet,
     ) {
         log::trace!("HyphenatedWordsAnalyzer::parse()");
         log::trace!(r#" word = "{}", word_lower = "{}" "#, word, word_lower);
 
-        // FIXME unimplemented
+        if !self.should_parse(morph, word_lower) {
+            return;
+        }
+        let pos = word_lower.find('-').expect("Checked in `should_parse`");
+        let (left, right) = (&word_lower[..pos], &word_lower[pos + 1..]);
+        let left_parses = self.parts(morph, left);
+        let right_parses = self.parts(morph, right);
+
+        self.parse_as_variable_both(morph, result, &left_parses, &right_parses, seen_parses);
+        // Parses similar to the ones of the previous step are kept,
+        // because their lexemes differ.
+        let mut seen = seen_parses.clone();
+        self.parse_as_fixed_left(morph, result, left, &right_parses, &mut seen);
+    }
+}
+
+impl HyphenatedWordsAnalyzer {
+    /// Requires exactly one hyphen in the middle of the word.
+    fn should_parse(&self, morph: &MorphAnalyzer, word_lower: &str) -> bool {
+        if word_lower.starts_with('-')
+            || word_lower.ends_with('-')
+            || word_lower.matches('-').count() != 1
+        {
+            return false;
+        }
+        !self.skip_known_prefixes
+            || morph
+                .dict
+                .prediction_prefixes
+                .prefixes(word_lower)
+                .is_empty()
+    }
+
+    /// Parses of a part of the word, except for the unknown ones.
+    fn parts(&self, morph: &MorphAnalyzer, part: &str) -> Vec<(StackAffix, Parsed)> {
+        morph
+            .parse(part)
+            .into_iter()
+            .filter(|parsed| {
+                let stack = &parsed.lex.stack;
+                stack.particle.is_none()
+                    && stack.stack.right.is_none()
+                    && stack.stack.left.stack.as_unknown().is_none()
+            })
+            .map(|parsed| (parsed.lex.stack.stack.left.clone(), parsed))
+            .collect()
+    }
 
-        //        let splitted: Vec<&str> = word_lower.split("-").collect();
-        //        if splitted.len() > 2 {
-        //            return
-        //        }
-        //        let (left, right) = (morph.parse(splitted[0]),
-        // morph.parse(splitted[1]));
+    /// Both parts are inflected, like in "человек-гора"; the tag is taken
+    /// from the left part.
+    ///
+    /// A left parse is paired with every right parse that agrees with it,
+    /// like pymorphy2 does. The score is the one of the left part alone:
+    /// the right part only has to agree, so its own ambiguity shouldn't lower
+    /// the score. Multiplying both scores would put "человек-гора" as
+    /// a masculine noun below the reading with the left part left unchanged.
+    fn parse_as_variable_both(
+        &self,
+        morph: &MorphAnalyzer,
+        result: &mut ParseResult,
+        left_parses: &[(StackAffix, Parsed)],
+        right_parses: &[(StackAffix, Parsed)],
+        seen_parses: &mut SeenSet,
+    ) {
+        for (left, left_parsed) in left_parses {
+            let features = similarity_features(left.get_tag(morph));
+            let rights = right_parses
+                .iter()
+                .map(|(right, _)| right)
+                .filter(|right| similarity_features(right.get_tag(morph)) == features);
+            for right in rights {
+                let stack = StackHyphenated::new(left.clone(), right.clone());
+                let lex = Lex::from_stack(morph, stack);
+                let score = left_parsed.score * self.estimate_decay;
+                add_parsed_if_not_seen(morph, result, seen_parses, Parsed::new(lex, score));
+            }
+        }
+    }
 
-        //        for particle in PARTICLES_AFTER_HYPHEN.iter() {
-        //            if word_lower.len() <= particle.len() ||
-        // !word_lower.ends_with(particle) {                continue
-        //            };
-        //            trace!(r#" particle: "{}" "#, particle);
-        //            let unsuffixed_word = &word_lower[.. word_lower.len() -
-        // particle.len()];            trace!(r#" unsuffixed_word: "{}"
-        // "#, unsuffixed_word);
-        //
-        //            'subparse: for parsed in morph.parse(unsuffixed_word) {
-        //                trace!(r#" parsed: {:?} "#, parsed);
-        //                let stack: StackHyphenated = match parsed.lex.stack {
-        //                    // If a word ends with with one of the particles,
-        // it can't ends with an another.                    
-        // Stack::HSP(_)               => continue 'subparse,
-        //                    Stack::Hyphenated(ref v)    => v.clone(),
-        //                    Stack::Affix(ref v)         => v.clone().into(),
-        //                    Stack::Source(ref v)        => v.clone().into(),
-        //                };
-        //                let container = StackParticle::Particle(stack,
-        // HyphenSeparatedParticle {                    particle:
-        // particle.to_string(),                });
-        //                result.push(Parsed {
-        //                    lex: Lex::from_stack(morph, container),
-        //                    score: Score::Real(1.0),
-        //                });
-        //            }
-        //        }
+    /// Only the right part is inflected, like in "интернет-магазин".
+    fn parse_as_fixed_left(
+        &self,
+        morph: &MorphAnalyzer,
+        result: &mut ParseResult,
+        left: &str,
+        right_parses: &[(StackAffix, Parsed)],
+        seen_parses: &mut SeenSet,
+    ) {
+        for (right, right_parsed) in right_parses {
+            let left = StackAffix::from(StackSource::from(Unknown::new(left)));
+            let stack = StackHyphenated::new(left, right.clone());
+            let lex = Lex::from_stack(morph, stack);
+            let score = right_parsed.score * self.estimate_decay;
+            add_parsed_if_not_seen(morph, result, seen_parses, Parsed::new(lex, score));
+        }
     }
 }
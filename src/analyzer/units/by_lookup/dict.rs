@@ -3,6 +3,7 @@ use crate::analyzer::MorphAnalyzer;
 use crate::container::stack::StackSource;
 use crate::container::{Dictionary, Lex, ParseResult, Parsed, Score, SeenSet, WordStruct};
 use crate::dawg::HH;
+use crate::opencorpora::OpencorporaTagReg;
 
 const DICT_SCORE: Score = Score::Real(1.0);
 
@@ -24,7 +25,7 @@ impl AnalyzerUnit for DictionaryAnalyzer {
         let para_data = morph
             .dict
             .words
-            .similar_items(word_lower, &morph.dict.char_substitutes);
+            .similar_items(word_lower, morph.substitutes());
         log::trace!(r#" para_data="{:?}" "#, para_data);
 
         // `fixed_word` is a word with proper substitute (e.g. ё) letters
@@ -37,4 +38,23 @@ impl AnalyzerUnit for DictionaryAnalyzer {
             }
         }
     }
+
+    fn tag<'m>(
+        &self,
+        morph: &'m MorphAnalyzer,
+        result: &mut Vec<&'m OpencorporaTagReg>,
+        _word: &str,
+        word_lower: &str,
+        _seen_parses: &mut SeenSet,
+    ) {
+        let para_data = morph
+            .dict
+            .words
+            .similar_items(word_lower, morph.substitutes());
+        for (_, parses) in para_data {
+            for HH(para_id, idx) in parses {
+                result.push(morph.dict.get_tag(para_id.into(), idx.into()));
+            }
+        }
+    }
 }
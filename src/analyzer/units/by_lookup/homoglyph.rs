@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use maplit::hashmap;
+
+use crate::analyzer::units::abc::AnalyzerUnit;
+use crate::analyzer::MorphAnalyzer;
+use crate::container::stack::StackSource;
+use crate::container::{Lex, ParseResult, Parsed, Repaired, SeenSet};
+
+/// Parse the word typed with Latin look-alikes of Cyrillic letters
+/// by looking up the word with the letters replaced in the dictionary.
+///
+/// Example: задaч (with a Latin "a") -> задач
+
+#[derive(Debug, Clone)]
+pub struct HomoglyphAnalyzer {
+    /// Latin letters (in the lower case) → Cyrillic ones
+    pub homoglyphs: HashMap<char, char>,
+    pub estimate_decay: f64,
+}
+
+impl Default for HomoglyphAnalyzer {
+    fn default() -> Self {
+        HomoglyphAnalyzer {
+            homoglyphs: hashmap! {
+                'a' => 'а',
+                'b' => 'в',
+                'c' => 'с',
+                'e' => 'е',
+                'h' => 'н',
+                'k' => 'к',
+                'm' => 'м',
+                'o' => 'о',
+                'p' => 'р',
+                't' => 'т',
+                'x' => 'х',
+                'y' => 'у',
+            },
+            estimate_decay: 0.9,
+        }
+    }
+}
+
+impl AnalyzerUnit for HomoglyphAnalyzer {
+    fn parse(
+        &self,
+        morph: &MorphAnalyzer,
+        result: &mut ParseResult,
+        word: &str,
+        word_lower: &str,
+        seen_parses: &mut SeenSet,
+    ) {
+        log::trace!("HomoglyphAnalyzer::parse()");
+        log::trace!(r#" word = "{}", word_lower = "{}" "#, word, word_lower);
+
+        let repaired = match self.repair(word_lower) {
+            Some(repaired) => repaired,
+            None => return,
+        };
+        log::trace!(r#" repaired: "{}" "#, repaired);
+
+        let mut parses = ParseResult::new();
+        morph
+            .units
+            .dictionary
+            .parse(morph, &mut parses, &repaired, &repaired, seen_parses);
+        for parsed in parses {
+            let source = match parsed.lex.stack.stack.left.stack {
+                StackSource::Dictionary(source) => source,
+                _ => continue,
+            };
+            let container = Repaired::new(word, source);
+            let lex = Lex::from_stack(morph, StackSource::from(container));
+            let score = parsed.score * self.estimate_decay;
+            result.push(Parsed::new(lex, score));
+        }
+    }
+}
+
+impl HomoglyphAnalyzer {
+    /// Replaces Latin letters of a word mixing Cyrillic and Latin letters;
+    /// returns `None` if the word is not mixed or can't be repaired.
+    pub fn repair(&self, word_lower: &str) -> Option<String> {
+        let mut has_cyrillic = false;
+        let mut has_latin = false;
+        let repaired = word_lower
+            .chars()
+            .map(|ch| match ch {
+                'a'..='z' => {
+                    has_latin = true;
+                    self.homoglyphs.get(&ch).cloned()
+                }
+                'а'..='я' | 'ё' => {
+                    has_cyrillic = true;
+                    Some(ch)
+                }
+                _ => Some(ch),
+            })
+            .collect::<Option<String>>()?;
+        match has_cyrillic && has_latin {
+            true => Some(repaired),
+            false => None,
+        }
+    }
+}
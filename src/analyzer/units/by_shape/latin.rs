@@ -42,4 +42,17 @@ impl AnalyzerUnit for LatinAnalyzer {
         let lex = Lex::from_stack(morph, StackSource::from(shaped));
         result.push(Parsed::new(lex, score));
     }
+
+    fn tag<'m>(
+        &self,
+        morph: &'m MorphAnalyzer,
+        result: &mut Vec<&'m OpencorporaTagReg>,
+        _word: &str,
+        word_lower: &str,
+        _seen_parses: &mut SeenSet,
+    ) {
+        if is_latin(word_lower) {
+            result.push(&morph.units.latin.tag);
+        }
+    }
 }
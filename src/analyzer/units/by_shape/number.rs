@@ -42,16 +42,39 @@ impl AnalyzerUnit for NumberAnalyzer {
         log::trace!("NumberAnalyzer::parse()");
         log::trace!(r#" word = "{}", word_lower = "{}" "#, word, word_lower);
 
-        // TODO Improve number parser [#12]
-        let shaped = if i128::from_str(word_lower).is_ok() {
-            Shaped::number(word_lower, false)
-        } else if f64::from_str(word_lower).is_ok() {
-            Shaped::number(word_lower, true)
-        } else {
-            return;
+        let shaped = match number_kind(word_lower) {
+            Some(is_float) => Shaped::number(word_lower, is_float),
+            None => return,
         };
         let score = shaped.score();
         let lex = Lex::from_stack(morph, StackSource::from(shaped));
         result.push(Parsed::new(lex, score));
     }
+
+    fn tag<'m>(
+        &self,
+        morph: &'m MorphAnalyzer,
+        result: &mut Vec<&'m OpencorporaTagReg>,
+        _word: &str,
+        word_lower: &str,
+        _seen_parses: &mut SeenSet,
+    ) {
+        match number_kind(word_lower) {
+            Some(true) => result.push(&morph.units.number.tag_real),
+            Some(false) => result.push(&morph.units.number.tag_int),
+            None => (),
+        }
+    }
+}
+
+/// Returns whether the word is a float number, or `None` if it's not a number.
+fn number_kind(word_lower: &str) -> Option<bool> {
+    // TODO Improve number parser [#12]
+    if i128::from_str(word_lower).is_ok() {
+        Some(false)
+    } else if f64::from_str(word_lower).is_ok() {
+        Some(true)
+    } else {
+        None
+    }
 }
@@ -42,4 +42,17 @@ impl AnalyzerUnit for PunctuationAnalyzer {
         let lex = Lex::from_stack(morph, StackSource::from(shaped));
         result.push(Parsed::new(lex, score));
     }
+
+    fn tag<'m>(
+        &self,
+        morph: &'m MorphAnalyzer,
+        result: &mut Vec<&'m OpencorporaTagReg>,
+        _word: &str,
+        word_lower: &str,
+        _seen_parses: &mut SeenSet,
+    ) {
+        if is_punctuation(word_lower) {
+            result.push(&morph.units.punct.tag);
+        }
+    }
 }
@@ -5,6 +5,7 @@ pub mod by_analogy;
 pub mod by_hyphen;
 pub mod by_lookup;
 pub mod by_shape;
+pub mod registry;
 
 pub mod unknown;
 
@@ -17,8 +18,10 @@ pub use self::by_hyphen::ha::HyphenAdverbAnalyzer;
 pub use self::by_hyphen::hsp::HyphenSeparatedParticleAnalyzer;
 pub use self::by_hyphen::hword::HyphenatedWordsAnalyzer;
 pub use self::by_lookup::dict::DictionaryAnalyzer;
+pub use self::by_lookup::homoglyph::HomoglyphAnalyzer;
 pub use self::by_shape::latin::LatinAnalyzer;
 pub use self::by_shape::number::NumberAnalyzer;
 pub use self::by_shape::punct::PunctuationAnalyzer;
 pub use self::by_shape::roman::RomanAnalyzer;
+pub use self::registry::UnitRegistry;
 pub use self::unknown::UnknownAnalyzer;
@@ -0,0 +1,56 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::analyzer::units::abc::AnalyzerUnit;
+
+pub type SharedUnit = Arc<dyn AnalyzerUnit + Send + Sync>;
+
+/// User-defined units, addressed by name from `UnitKind::Custom`.
+#[derive(Default, Clone)]
+pub struct UnitRegistry {
+    units: Vec<(&'static str, SharedUnit)>,
+}
+
+impl UnitRegistry {
+    /// Registers the `unit` under the `name`, replacing a unit registered
+    /// under the same name before.
+    pub fn register<U>(&mut self, name: &'static str, unit: U)
+    where
+        U: AnalyzerUnit + Send + Sync + 'static,
+    {
+        self.register_shared(name, Arc::new(unit))
+    }
+
+    pub fn register_shared(&mut self, name: &'static str, unit: SharedUnit) {
+        match self.units.iter_mut().find(|(n, _)| *n == name) {
+            Some(entry) => entry.1 = unit,
+            None => self.units.push((name, unit)),
+        }
+    }
+
+    pub fn unregister(&mut self, name: &str) -> Option<SharedUnit> {
+        let pos = self.units.iter().position(|(n, _)| *n == name)?;
+        Some(self.units.remove(pos).1)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&(dyn AnalyzerUnit + Send + Sync)> {
+        self.units
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, unit)| unit.as_ref())
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.units.iter().map(|&(name, _)| name)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.units.is_empty()
+    }
+}
+
+impl fmt::Debug for UnitRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
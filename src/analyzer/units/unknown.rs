@@ -36,4 +36,17 @@ impl AnalyzerUnit for UnknownAnalyzer {
             result.push(Parsed::new(lex, SCORE));
         }
     }
+
+    fn tag<'m>(
+        &self,
+        morph: &'m MorphAnalyzer,
+        result: &mut Vec<&'m OpencorporaTagReg>,
+        _word: &str,
+        _word_lower: &str,
+        seen_parses: &mut SeenSet,
+    ) {
+        if seen_parses.is_empty() {
+            result.push(&morph.units.unknown.tag);
+        }
+    }
 }
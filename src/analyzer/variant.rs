@@ -0,0 +1,99 @@
+use std::cmp::Reverse;
+
+use crate::opencorpora::kind::{Flag, FlagGroup, Flags};
+
+/// Which forms `Lex::inflect` takes when several forms fit equally well,
+/// like "рыбой" and "рыбою".
+///
+/// By default forms with variant grammemes ("V-oy", "Cmp2", ...) and
+/// archaic, informal, erroneous or distorted ones are avoided. The policy
+/// only chooses among forms with all the required grammemes, so "стол"
+/// still goes to the informal "столу" for "loc2".
+///
+/// ```
+/// use rsmorphy::analyzer::{MorphAnalyzerBuilder, VariantPolicy};
+/// use rsmorphy::opencorpora::kind::Flag;
+/// use rsmorphy::prelude::*;
+///
+/// let ablt = GrammemeSet::new("ablt");
+/// let morph = MorphAnalyzer::from_file(rsmorphy::dict_ru::DICT_PATH);
+/// let fish = &morph.parse("рыба")[0].lex;
+/// assert_eq!(fish.inflect(&morph, &ablt).unwrap().get_word(), "рыбой");
+///
+/// let morph = MorphAnalyzerBuilder::new()
+///     .variant_policy(VariantPolicy::default().prefer(Flag::VOy))
+///     .build(morph.dict.clone());
+/// assert_eq!(fish.inflect(&morph, &ablt).unwrap().get_word(), "рыбою");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VariantPolicy {
+    avoided: Flags,
+    preferred: Flags,
+}
+
+impl Default for VariantPolicy {
+    fn default() -> Self {
+        let avoided = Flag::ALL
+            .iter()
+            .cloned()
+            .filter(|&flag| match flag {
+                Flag::Arch | Flag::Infr | Flag::Erro | Flag::Dist => true,
+                flag => flag.group() == FlagGroup::Variant,
+            })
+            .collect();
+        VariantPolicy {
+            avoided,
+            preferred: Flags::new(),
+        }
+    }
+}
+
+impl VariantPolicy {
+    pub fn new() -> Self {
+        VariantPolicy::default()
+    }
+
+    /// Neither avoids nor prefers any forms, so the order of forms
+    /// in the dictionary decides.
+    pub fn neutral() -> Self {
+        VariantPolicy {
+            avoided: Flags::new(),
+            preferred: Flags::new(),
+        }
+    }
+
+    /// Takes forms with the flag on a par with others.
+    pub fn allow(mut self, flag: Flag) -> Self {
+        self.avoided.remove(flag);
+        self.preferred.remove(flag);
+        self
+    }
+
+    /// Takes forms with the flag over others.
+    pub fn prefer(mut self, flag: Flag) -> Self {
+        self.avoided.remove(flag);
+        self.preferred.insert(flag);
+        self
+    }
+
+    /// Takes forms with the flag only if nothing else fits as well.
+    pub fn avoid(mut self, flag: Flag) -> Self {
+        self.preferred.remove(flag);
+        self.avoided.insert(flag);
+        self
+    }
+
+    pub fn is_avoided(self, flag: Flag) -> bool {
+        self.avoided.contains(flag)
+    }
+
+    pub fn is_preferred(self, flag: Flag) -> bool {
+        self.preferred.contains(flag)
+    }
+
+    /// Ranks a form by its flags; greater ranks are better.
+    pub fn rank(self, flags: Flags) -> (usize, Reverse<usize>) {
+        let count = |policy: Flags| flags.iter().filter(|&flag| policy.contains(flag)).count();
+        (count(self.preferred), Reverse(count(self.avoided)))
+    }
+}
@@ -0,0 +1,24 @@
+//! Compiles a dictionary directory into a single file for
+//! `MorphAnalyzer::from_bundle`.
+
+use std::env;
+use std::process;
+
+use rsmorphy::bundle;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (dict_path, bundle_path) = match args.as_slice() {
+        [bundle_path] => (rsmorphy::dict_ru::DICT_PATH, bundle_path.as_str()),
+        [dict_path, bundle_path] => (dict_path.as_str(), bundle_path.as_str()),
+        _ => {
+            eprintln!("Usage: rsmorphy-bundle [DICT_DIR] BUNDLE_FILE");
+            eprintln!("Without DICT_DIR, the built-in Russian dictionary is compiled.");
+            process::exit(2);
+        }
+    };
+    if let Err(e) = bundle::compile(dict_path, bundle_path) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
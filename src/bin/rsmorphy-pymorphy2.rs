@@ -0,0 +1,250 @@
+//! Compares rsmorphy with pymorphy2.
+//!
+//! Both dump and compare JSON lines like `{"word": "стали", "parses": [...]}`,
+//! with parses as `rsmorphy::container::Pymorphy2Parse`. Methods stacks
+//! are not compared, so a pymorphy2 dump may be made without them:
+//!
+//! ```python
+//! import json, pymorphy2
+//!
+//! morph = pymorphy2.MorphAnalyzer()
+//! for word in open("words.txt", encoding="utf-8").read().split():
+//!     parses = [[p.word, str(p.tag), p.normal_form, p.score, []] for p in morph.parse(word)]
+//!     print(json.dumps({"word": word, "parses": parses}, ensure_ascii=False))
+//! ```
+//!
+//! Like `diff`, `compare` exits with 1 if there are differences, and with 2
+//! if there is trouble.
+
+use std::collections::BTreeSet;
+use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::process;
+
+use serde_derive::{Deserialize, Serialize};
+
+use rsmorphy::container::Pymorphy2Parse;
+use rsmorphy::MorphAnalyzer;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DumpLine {
+    word: String,
+    parses: Vec<Pymorphy2Parse>,
+}
+
+#[derive(Debug, Default)]
+struct Stats {
+    words: usize,
+    differ: usize,
+    tags: usize,
+    lemmas: usize,
+    order: usize,
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let result = match args.as_slice() {
+        ["dump"] => dump(io::stdin().lock()),
+        ["dump", words_path] => open(words_path).and_then(dump),
+        ["compare", dump_path] => open(dump_path).and_then(compare),
+        _ => {
+            eprintln!("Usage: rsmorphy-pymorphy2 dump [WORDS_FILE]");
+            eprintln!("       rsmorphy-pymorphy2 compare PYMORPHY2_DUMP_FILE");
+            eprintln!("`dump` prints parses of words, one per line, in the pymorphy2 shape;");
+            eprintln!("`compare` reports words where rsmorphy differs from the dump.");
+            process::exit(2);
+        }
+    };
+    match result {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    }
+}
+
+fn open(path: &str) -> Result<BufReader<File>, String> {
+    File::open(path)
+        .map(BufReader::new)
+        .map_err(|e| format!("{}: {}", path, e))
+}
+
+fn dump<R: BufRead>(words: R) -> Result<bool, String> {
+    let morph = MorphAnalyzer::from_file(rsmorphy::dict_ru::DICT_PATH);
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    for word in words.lines() {
+        let word = word.map_err(|e| e.to_string())?;
+        let word = word.trim();
+        if word.is_empty() {
+            continue;
+        }
+        let line = DumpLine {
+            word: word.to_owned(),
+            parses: morph
+                .parse(word)
+                .iter()
+                .map(|parsed| parsed.to_pymorphy2(&morph))
+                .collect(),
+        };
+        let json = serde_json::to_string(&line).map_err(|e| e.to_string())?;
+        writeln!(out, "{}", json).map_err(|e| e.to_string())?;
+    }
+    Ok(true)
+}
+
+fn compare<R: BufRead>(dump: R) -> Result<bool, String> {
+    let morph = MorphAnalyzer::from_file(rsmorphy::dict_ru::DICT_PATH);
+    let stats = compare_with(&morph, dump)?;
+    eprintln!(
+        "{} words, {} differ: {} in tags, {} in lemmas, {} in order",
+        stats.words, stats.differ, stats.tags, stats.lemmas, stats.order
+    );
+    Ok(stats.differ == 0)
+}
+
+fn compare_with<R: BufRead>(morph: &MorphAnalyzer, dump: R) -> Result<Stats, String> {
+    let mut stats = Stats::default();
+    for (n, line) in dump.lines().enumerate() {
+        let line = line.map_err(|e| e.to_string())?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let expected: DumpLine =
+            serde_json::from_str(&line).map_err(|e| format!("line {}: {}", n + 1, e))?;
+        let actual: Vec<Pymorphy2Parse> = morph
+            .parse(&expected.word)
+            .iter()
+            .map(|parsed| parsed.to_pymorphy2(morph))
+            .collect();
+        stats.words += 1;
+        if report(&expected.word, &expected.parses, &actual, &mut stats) {
+            stats.differ += 1;
+        }
+    }
+    Ok(stats)
+}
+
+/// Prints differences of the word, one per line; returns whether there
+/// are any. The order is compared among the parses both analyzers give.
+fn report(
+    word: &str,
+    expected: &[Pymorphy2Parse],
+    actual: &[Pymorphy2Parse],
+    stats: &mut Stats,
+) -> bool {
+    let tags = |parses: &[Pymorphy2Parse]| -> BTreeSet<String> {
+        parses.iter().map(|p| p.tag.clone()).collect()
+    };
+    let lemmas = |parses: &[Pymorphy2Parse]| -> BTreeSet<String> {
+        parses.iter().map(|p| p.normal_form.clone()).collect()
+    };
+    let order = |parses: &[Pymorphy2Parse]| -> Vec<String> {
+        parses
+            .iter()
+            .map(|p| format!("{} {}", p.normal_form, p.tag))
+            .collect()
+    };
+
+    let mut differs = false;
+    if report_sets(word, "tags", tags(expected), tags(actual)) {
+        stats.tags += 1;
+        differs = true;
+    }
+    if report_sets(word, "lemmas", lemmas(expected), lemmas(actual)) {
+        stats.lemmas += 1;
+        differs = true;
+    }
+    let (expected, actual) = (order(expected), order(actual));
+    let common = |parses: &[String], other: &[String]| -> Vec<String> {
+        parses
+            .iter()
+            .filter(|parse| other.contains(parse))
+            .cloned()
+            .collect()
+    };
+    let (expected, actual) = (common(&expected, &actual), common(&actual, &expected));
+    if expected != actual {
+        println!(
+            "{}\torder\t{}\t{}",
+            word,
+            expected.join("; "),
+            actual.join("; ")
+        );
+        stats.order += 1;
+        differs = true;
+    }
+    differs
+}
+
+/// Prints "word, what differs, what rsmorphy lacks, what rsmorphy adds".
+fn report_sets(
+    word: &str,
+    what: &str,
+    expected: BTreeSet<String>,
+    actual: BTreeSet<String>,
+) -> bool {
+    if expected == actual {
+        return false;
+    }
+    let lacks: Vec<_> = expected.difference(&actual).cloned().collect();
+    let adds: Vec<_> = actual.difference(&expected).cloned().collect();
+    println!(
+        "{}\t{}\t-{}\t+{}",
+        word,
+        what,
+        lacks.join("; "),
+        adds.join("; ")
+    );
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_dump() {
+        let morph = MorphAnalyzer::from_file(rsmorphy::dict_ru::DICT_PATH);
+        let parses = |word: &str| -> Vec<Pymorphy2Parse> {
+            let parsed = morph.parse(word);
+            parsed.iter().map(|p| p.to_pymorphy2(&morph)).collect()
+        };
+        let line = |word: &str, parses: Vec<Pymorphy2Parse>| {
+            let word = word.to_owned();
+            serde_json::to_string(&DumpLine { word, parses }).unwrap()
+        };
+        let compare = |dump: Vec<String>| {
+            let s = compare_with(&morph, dump.join("\n").as_bytes()).unwrap();
+            (s.words, s.differ, s.tags, s.lemmas, s.order)
+        };
+
+        // Methods stacks are not compared.
+        let mut without_methods = parses("42");
+        without_methods[0].methods_stack.clear();
+        let dump = vec![
+            line("стали", parses("стали")),
+            String::new(),
+            line("42", without_methods),
+        ];
+        assert_eq!(compare(dump), (2, 0, 0, 0, 0));
+
+        let mut reordered = parses("стали");
+        reordered.swap(0, 1);
+        let mut retagged = parses("42");
+        retagged[0].tag = "NUMB,real".to_owned();
+        let mut fewer = parses("стали");
+        fewer.truncate(1);
+        fewer[0].normal_form = "стал".to_owned();
+        let dump = vec![
+            line("стали", reordered),
+            line("42", retagged),
+            line("стали", fewer),
+        ];
+        assert_eq!(compare(dump), (3, 3, 2, 1, 1));
+    }
+}
@@ -0,0 +1,220 @@
+//! A dictionary compiled into a single uncompressed file.
+//!
+//! The bundle is memory-mapped, and the DAWGs, the paradigms table and
+//! the gramtabs borrow from the mapping, so processes loading the same
+//! bundle share its pages instead of owning copies. Tags of the gramtab
+//! are parsed on the first use, see `Gramtab`.
+//!
+//! The other small sections are kept as JSON and parsed on load.
+//! Errors about a section name it as a file in the bundle,
+//! like `ru.bundle/meta.json`.
+//!
+//! Layout (little-endian):
+//!
+//! ```text
+//! magic       8 bytes         b"RSMORPHY"
+//! version     u32
+//! count       u32
+//! sections    count × 64      name (48 bytes, zero-padded), offset u64, size u64
+//! data        ...             every section is aligned to 8 bytes
+//! ```
+
+pub mod table;
+pub mod writer;
+
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::str;
+use std::sync::Arc;
+
+use byteorder::{ByteOrder, LittleEndian};
+use memmap2::Mmap;
+
+use crate::dawg::dictionary::Dictionary as DawgDictionary;
+use crate::dawg::guide::Guide;
+use crate::dawg::{CompletionDawg, Dawg, DawgValue};
+use crate::opencorpora::dictionary::Loader;
+use crate::opencorpora::error::DictionaryError;
+use crate::opencorpora::paradigm::Paradigms;
+
+pub use self::table::{bytes_of, Plain, StringTable, Table};
+pub use self::writer::{compile, BundleWriter};
+
+pub const MAGIC: &[u8; 8] = b"RSMORPHY";
+pub const VERSION: u32 = 3;
+pub const ALIGN: usize = 8;
+pub const NAME_LEN: usize = 48;
+pub const HEADER_LEN: usize = 16;
+pub const ENTRY_LEN: usize = NAME_LEN + 16;
+
+#[derive(Debug, Clone)]
+struct Section {
+    name: String,
+    offset: usize,
+    size: usize,
+}
+
+/// A memory-mapped bundle file.
+#[derive(Debug, Clone)]
+pub struct Bundle {
+    path: PathBuf,
+    map: Arc<Mmap>,
+    sections: Vec<Section>,
+}
+
+impl Bundle {
+    pub fn open<P>(p: P) -> Result<Self, DictionaryError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = p.as_ref().to_owned();
+        if cfg!(target_endian = "big") {
+            return Err(DictionaryError::bundle(
+                path,
+                "big-endian targets are not supported",
+            ));
+        }
+        let map = File::open(&path)
+            // Safe as long as the file isn't modified while it's mapped;
+            // bundles are never written in place.
+            .and_then(|file| unsafe { Mmap::map(&file) })
+            .map_err(|source| DictionaryError::Io {
+                file: path.clone(),
+                source,
+            })?;
+        let sections =
+            read_sections(&map).map_err(|reason| DictionaryError::bundle(&path, reason))?;
+        let map = Arc::new(map);
+        Ok(Bundle {
+            path,
+            map,
+            sections,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn section_names(&self) -> impl Iterator<Item = &str> {
+        self.sections.iter().map(|section| section.name.as_str())
+    }
+
+    fn section(&self, name: &str) -> Result<&Section, DictionaryError> {
+        self.sections
+            .iter()
+            .find(|section| section.name == name)
+            .ok_or_else(|| DictionaryError::bundle(&self.path, format!("no section `{}`", name)))
+    }
+
+    pub fn bytes(&self, name: &str) -> Result<&[u8], DictionaryError> {
+        let section = self.section(name)?;
+        Ok(&self.map[section.offset..section.offset + section.size])
+    }
+
+    /// Borrows the section as an array of `T`.
+    pub fn table<T>(&self, name: &str) -> Result<Table<T>, DictionaryError>
+    where
+        T: Plain,
+    {
+        let section = self.section(name)?;
+        Table::mapped(self.map.clone(), section.offset, section.size).ok_or_else(|| {
+            DictionaryError::bundle(&self.path, format!("misaligned section `{}`", name))
+        })
+    }
+}
+
+/// The section of a dictionary file: the name without `.gz`, and the `part`
+/// for files split into several tables.
+pub fn section_name(file_name: &str, part: Option<&str>) -> String {
+    let name = file_name.trim_end_matches(".gz");
+    match part {
+        Some(part) => format!("{}/{}", name, part),
+        None => name.to_owned(),
+    }
+}
+
+impl Loader for Bundle {
+    /// The section as if it were a file in the bundle, like `ru.bundle/meta.json`.
+    fn path(&self, name: &str) -> PathBuf {
+        self.path.join(section_name(name, None))
+    }
+
+    fn json<T>(&self, name: &str) -> Result<T, DictionaryError>
+    where
+        for<'de> T: ::serde::Deserialize<'de>,
+    {
+        let data = self.bytes(&section_name(name, None))?;
+        serde_json::from_slice(data).map_err(|source| DictionaryError::Json {
+            file: Loader::path(self, name),
+            source,
+        })
+    }
+
+    fn strings(&self, name: &str) -> Result<StringTable, DictionaryError> {
+        let offsets = self.table(&section_name(name, Some("offsets")))?;
+        let bytes = self.table(&section_name(name, Some("bytes")))?;
+        StringTable::from_tables(offsets, bytes)
+            .ok_or_else(|| DictionaryError::bundle(Loader::path(self, name), "broken strings"))
+    }
+
+    fn paradigms(&self, name: &str) -> Result<Paradigms, DictionaryError> {
+        let offsets: Table<u32> = self.table(&section_name(name, Some("offsets")))?;
+        let entries = self.table(&section_name(name, Some("entries")))?;
+        let sorted = offsets.windows(2).all(|w| w[0] <= w[1]);
+        match offsets.last() {
+            Some(&end) if sorted && end as usize <= entries.len() => {
+                Ok(Paradigms::new(offsets, entries))
+            }
+            _ => Err(DictionaryError::bundle(
+                Loader::path(self, name),
+                "broken paradigm offsets",
+            )),
+        }
+    }
+
+    fn dawg(&self, name: &str) -> Result<Dawg, DictionaryError> {
+        let units = self.table(&section_name(name, Some("units")))?;
+        Ok(Dawg::new(DawgDictionary::new(units)))
+    }
+
+    fn completion_dawg<V>(&self, name: &str) -> Result<CompletionDawg<V>, DictionaryError>
+    where
+        V: DawgValue,
+    {
+        let guide = self.table(&section_name(name, Some("guide")))?;
+        Ok(CompletionDawg::new(self.dawg(name)?, Guide::new(guide)))
+    }
+}
+
+fn read_sections(map: &[u8]) -> Result<Vec<Section>, String> {
+    if map.len() < HEADER_LEN || &map[..MAGIC.len()] != MAGIC {
+        return Err("not a bundle".into());
+    }
+    let version = LittleEndian::read_u32(&map[8..12]);
+    if version != VERSION {
+        return Err(format!("unsupported version {}", version));
+    }
+    let count = LittleEndian::read_u32(&map[12..16]) as usize;
+    let entries = map
+        .get(HEADER_LEN..HEADER_LEN + count * ENTRY_LEN)
+        .ok_or("truncated section table")?;
+    entries
+        .chunks(ENTRY_LEN)
+        .map(|entry| {
+            let name = &entry[..NAME_LEN];
+            let name = &name[..name.iter().position(|&b| b == 0).unwrap_or(NAME_LEN)];
+            let name = str::from_utf8(name).map_err(|e| format!("bad section name: {}", e))?;
+            let offset = LittleEndian::read_u64(&entry[NAME_LEN..NAME_LEN + 8]) as usize;
+            let size = LittleEndian::read_u64(&entry[NAME_LEN + 8..]) as usize;
+            match offset.checked_add(size) {
+                Some(end) if end <= map.len() => Ok(Section {
+                    name: name.to_owned(),
+                    offset,
+                    size,
+                }),
+                _ => Err(format!("truncated section `{}`", name)),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,173 @@
+use std::fmt;
+use std::mem::{align_of, size_of, size_of_val};
+use std::ops::{Deref, Index};
+use std::slice;
+use std::str;
+use std::sync::Arc;
+
+use memmap2::Mmap;
+
+/// Plain old data which may be viewed right in the mapped bundle.
+///
+/// # Safety
+///
+/// Implementors must be `#[repr(C)]` (or primitive) without padding,
+/// and every bit pattern must be a valid value.
+pub unsafe trait Plain: Copy + 'static {}
+
+unsafe impl Plain for u8 {}
+unsafe impl Plain for u16 {}
+unsafe impl Plain for u32 {}
+
+/// A read-only array which is either owned or borrowed from a shared
+/// memory mapping of a bundle.
+#[derive(Clone)]
+pub enum Table<T>
+where
+    T: Plain,
+{
+    Owned(Vec<T>),
+    Mapped {
+        map: Arc<Mmap>,
+        offset: usize,
+        len: usize,
+    },
+}
+
+impl<T> Table<T>
+where
+    T: Plain,
+{
+    /// Views `size` bytes of the `map` from the `offset` as an array of `T`;
+    /// returns `None` if the range is out of bounds or misaligned.
+    pub fn mapped(map: Arc<Mmap>, offset: usize, size: usize) -> Option<Self> {
+        let end = offset.checked_add(size)?;
+        let aligned = (map.as_ptr() as usize + offset).is_multiple_of(align_of::<T>());
+        if end > map.len() || !aligned || !size.is_multiple_of(size_of::<T>()) {
+            return None;
+        }
+        let len = size / size_of::<T>();
+        Some(Table::Mapped { map, offset, len })
+    }
+
+    pub fn is_mapped(&self) -> bool {
+        match self {
+            Table::Owned(_) => false,
+            Table::Mapped { .. } => true,
+        }
+    }
+}
+
+/// Views the items as raw bytes, the way they're stored in a bundle.
+pub fn bytes_of<T>(items: &[T]) -> &[u8]
+where
+    T: Plain,
+{
+    // Safe: `T: Plain` has no padding, so all its bytes are initialized.
+    unsafe { slice::from_raw_parts(items.as_ptr() as *const u8, size_of_val(items)) }
+}
+
+impl<T> Deref for Table<T>
+where
+    T: Plain,
+{
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        match self {
+            Table::Owned(vec) => vec,
+            // Safe: the bounds and the alignment are checked in `Table::mapped`,
+            // and any bit pattern is a valid `T: Plain`.
+            Table::Mapped { map, offset, len } => unsafe {
+                slice::from_raw_parts(map.as_ptr().add(*offset) as *const T, *len)
+            },
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for Table<T>
+where
+    T: Plain,
+{
+    fn from(vec: Vec<T>) -> Self {
+        Table::Owned(vec)
+    }
+}
+
+impl<T> fmt::Debug for Table<T>
+where
+    T: Plain + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let items: &[T] = self;
+        items.fmt(f)
+    }
+}
+
+/// Strings stored back to back, either owned or borrowed from a shared
+/// memory mapping of a bundle.
+#[derive(Debug, Clone)]
+pub struct StringTable {
+    /// `offsets[i]..offsets[i + 1]` is the range of the `i`-th string
+    pub offsets: Table<u32>,
+    pub bytes: Table<u8>,
+}
+
+impl StringTable {
+    pub fn new<S>(strings: &[S]) -> Self
+    where
+        S: AsRef<str>,
+    {
+        let mut offsets = Vec::with_capacity(strings.len() + 1);
+        let mut bytes = Vec::new();
+        offsets.push(0);
+        for string in strings {
+            bytes.extend_from_slice(string.as_ref().as_bytes());
+            offsets.push(bytes.len() as u32);
+        }
+        let offsets = offsets.into();
+        let bytes = bytes.into();
+        StringTable { offsets, bytes }
+    }
+
+    /// Returns `None` if the offsets are out of order or bounds,
+    /// or some string is not valid UTF-8.
+    pub fn from_tables(offsets: Table<u32>, bytes: Table<u8>) -> Option<Self> {
+        let table = StringTable { offsets, bytes };
+        let sorted = table.offsets.windows(2).all(|w| w[0] <= w[1]);
+        let end = *table.offsets.last()? as usize;
+        if !sorted || end > table.bytes.len() {
+            return None;
+        }
+        for idx in 0..table.len() {
+            table.get(idx)?;
+        }
+        Some(table)
+    }
+
+    pub fn len(&self) -> usize {
+        self.offsets.len().saturating_sub(1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, idx: usize) -> Option<&str> {
+        let start = *self.offsets.get(idx)? as usize;
+        let end = *self.offsets.get(idx + 1)? as usize;
+        str::from_utf8(self.bytes.get(start..end)?).ok()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        (0..self.len()).map(move |idx| &self[idx])
+    }
+}
+
+impl Index<usize> for StringTable {
+    type Output = str;
+
+    fn index(&self, idx: usize) -> &str {
+        self.get(idx).expect("string index out of range")
+    }
+}
@@ -0,0 +1,139 @@
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use byteorder::{LittleEndian, WriteBytesExt};
+
+use crate::bundle::{
+    bytes_of, section_name, Plain, StringTable, ALIGN, ENTRY_LEN, HEADER_LEN, MAGIC, NAME_LEN,
+    VERSION,
+};
+use crate::dawg::{CompletionDawg, Dawg, DawgValue};
+use crate::opencorpora::dictionary::{Dictionary, PathLoader};
+use crate::opencorpora::error::DictionaryError;
+
+const JSON_FILES: &[&str] = &["meta.json.gz", "suffixes.json.gz", "grammemes.json.gz"];
+
+/// Collects sections and writes them in the bundle layout.
+#[derive(Debug, Default, Clone)]
+pub struct BundleWriter {
+    sections: Vec<(String, Vec<u8>)>,
+}
+
+impl BundleWriter {
+    pub fn new() -> Self {
+        BundleWriter::default()
+    }
+
+    pub fn add<N>(&mut self, name: N, data: Vec<u8>)
+    where
+        N: Into<String>,
+    {
+        let name = name.into();
+        assert!(name.len() <= NAME_LEN, "Too long section name: {}", name);
+        self.sections.push((name, data));
+    }
+
+    pub fn add_table<N, T>(&mut self, name: N, items: &[T])
+    where
+        N: Into<String>,
+        T: Plain,
+    {
+        self.add(name, bytes_of(items).to_vec())
+    }
+
+    pub fn add_strings(&mut self, file_name: &str, strings: &StringTable) {
+        self.add_table(section_name(file_name, Some("offsets")), &strings.offsets);
+        self.add_table(section_name(file_name, Some("bytes")), &strings.bytes);
+    }
+
+    pub fn add_dawg(&mut self, file_name: &str, dawg: &Dawg) {
+        let units = &dawg.dictionary().units;
+        self.add_table(section_name(file_name, Some("units")), units);
+    }
+
+    pub fn add_completion_dawg<V>(&mut self, file_name: &str, dawg: &CompletionDawg<V>)
+    where
+        V: DawgValue,
+    {
+        self.add_dawg(file_name, dawg.dawg());
+        self.add_table(section_name(file_name, Some("guide")), &dawg.guide().units);
+    }
+
+    pub fn write_to<W>(&self, w: &mut W) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let align = |pos: usize| pos.div_ceil(ALIGN) * ALIGN;
+
+        w.write_all(MAGIC)?;
+        w.write_u32::<LittleEndian>(VERSION)?;
+        w.write_u32::<LittleEndian>(self.sections.len() as u32)?;
+
+        let mut offset = align(HEADER_LEN + ENTRY_LEN * self.sections.len());
+        for (name, data) in &self.sections {
+            let mut padded_name = [0; NAME_LEN];
+            padded_name[..name.len()].copy_from_slice(name.as_bytes());
+            w.write_all(&padded_name)?;
+            w.write_u64::<LittleEndian>(offset as u64)?;
+            w.write_u64::<LittleEndian>(data.len() as u64)?;
+            offset = align(offset + data.len());
+        }
+
+        let mut pos = HEADER_LEN + ENTRY_LEN * self.sections.len();
+        for (_, data) in &self.sections {
+            let padding = align(pos) - pos;
+            w.write_all(&[0; ALIGN][..padding])?;
+            w.write_all(data)?;
+            pos += padding + data.len();
+        }
+        Ok(())
+    }
+}
+
+/// Compiles the dictionary from the `dict_path` directory into a single
+/// bundle file to load with `Dictionary::from_bundle`.
+///
+/// The bundle is written next to `bundle_path` and then renamed, so
+/// processes that have the old bundle mapped are not affected.
+pub fn compile<P, Q>(dict_path: P, bundle_path: Q) -> Result<(), DictionaryError>
+where
+    P: AsRef<Path>,
+    Q: AsRef<Path>,
+{
+    let dict_path = dict_path.as_ref();
+    let bundle_path = bundle_path.as_ref();
+    let load = PathLoader::new(dict_path);
+    let dict = Dictionary::try_from_file(dict_path)?;
+
+    let mut bundle = BundleWriter::new();
+    for name in JSON_FILES {
+        bundle.add(section_name(name, None), load.raw(name)?);
+    }
+    let gramtab = &dict.gramtab;
+    bundle.add_strings("gramtab-opencorpora-int.json.gz", gramtab.int_strings());
+    bundle.add_strings("gramtab-opencorpora-ext.json.gz", gramtab.ext_strings());
+    let paradigms = section_name("paradigms.array.gz", Some("offsets"));
+    bundle.add_table(paradigms, &dict.paradigms.offsets);
+    let paradigms = section_name("paradigms.array.gz", Some("entries"));
+    bundle.add_table(paradigms, &dict.paradigms.entries);
+    bundle.add_completion_dawg("words.dawg.gz", &dict.words);
+    bundle.add_completion_dawg("p_t_given_w.intdawg.gz", &dict.p_t_given_w);
+    bundle.add_dawg("prediction-prefixes.dawg.gz", &dict.prediction_prefixes);
+    for (i, dawg) in dict.prediction_suffixes_dawgs.iter().enumerate() {
+        bundle.add_completion_dawg(&format!("prediction-suffixes-{}.dawg.gz", i), dawg);
+    }
+
+    let mut tmp_path = bundle_path.as_os_str().to_owned();
+    tmp_path.push(".tmp");
+    let write = || -> io::Result<()> {
+        let mut w = BufWriter::new(File::create(&tmp_path)?);
+        bundle.write_to(&mut w)?;
+        w.into_inner()?.sync_all()?;
+        fs::rename(&tmp_path, bundle_path)
+    };
+    write().map_err(|source| DictionaryError::Io {
+        file: bundle_path.to_owned(),
+        source,
+    })
+}
@@ -0,0 +1,175 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::analyzer::MorphAnalyzer;
+use crate::container::abc::*;
+use crate::container::decode::*;
+use crate::container::paradigm::ParadigmId;
+use crate::container::stack::StackSource;
+use crate::container::{Lex, Score, WordStruct};
+use crate::opencorpora::tag::OpencorporaTagReg;
+
+/// A word recognized by a user-defined unit.
+///
+/// The tag is taken from `AnalyzerUnit::tags()` of the unit registered
+/// under the `unit` name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Custom {
+    pub unit: String,
+    pub word_lower: WordStruct,
+    pub normal_form: Option<String>,
+    pub tag_idx: u16,
+}
+
+impl Custom {
+    pub fn new<U>(unit: U, word_lower: WordStruct, tag_idx: u16) -> Self
+    where
+        U: Into<String>,
+    {
+        let unit = unit.into();
+        Custom {
+            unit,
+            word_lower,
+            normal_form: None,
+            tag_idx,
+        }
+    }
+
+    /// Sets the normal form if it differs from the word.
+    pub fn with_normal_form<N>(mut self, normal_form: N) -> Self
+    where
+        N: Into<String>,
+    {
+        let normal_form = normal_form.into();
+        self.normal_form = match normal_form == self.word_lower.word() {
+            true => None,
+            false => Some(normal_form),
+        };
+        self
+    }
+
+    pub fn iter_lexeme<'s: 'i, 'm: 'i, 'i>(
+        &'s self,
+        morph: &'m MorphAnalyzer,
+    ) -> impl Iterator<Item = Lex> + 'i {
+        (0..1).map(move |_| Lex::from_stack(morph, StackSource::from(self.clone())))
+    }
+
+    fn normal_form(&self) -> &str {
+        self.normal_form
+            .as_deref()
+            .unwrap_or_else(|| self.word_lower.word())
+    }
+}
+
+impl Source for Custom {
+    fn score(&self) -> Score {
+        Score::Fake(1.0)
+    }
+
+    fn is_lemma(&self) -> bool {
+        self.normal_form.is_none()
+    }
+
+    fn is_known(&self) -> bool {
+        self.word_lower.is_known()
+    }
+
+    fn get_word(&self) -> Cow<'_, str> {
+        Cow::from(self.word_lower.word())
+    }
+
+    fn get_normal_form(&self, _morph: &MorphAnalyzer) -> Cow<'_, str> {
+        Cow::from(self.normal_form())
+    }
+
+    fn get_tag<'m>(&self, morph: &'m MorphAnalyzer) -> &'m OpencorporaTagReg {
+        morph
+            .units
+            .custom
+            .get(&self.unit)
+            .and_then(|unit| unit.tags().get(self.tag_idx as usize))
+            .unwrap_or(&morph.units.unknown.tag)
+    }
+
+    fn try_get_para_id(&self) -> Option<ParadigmId> {
+        None
+    }
+
+    fn write_word<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
+        write!(f, "{}", self.word_lower.word())
+    }
+
+    fn write_normal_form<W: fmt::Write>(&self, f: &mut W, _morph: &MorphAnalyzer) -> fmt::Result {
+        write!(f, "{}", self.normal_form())
+    }
+
+    fn get_lexeme(&self, morph: &MorphAnalyzer) -> Vec<Lex> {
+        self.iter_lexeme(morph).collect()
+    }
+
+    fn get_lemma(&self, morph: &MorphAnalyzer) -> Lex {
+        self.iter_lexeme(morph).next().unwrap()
+    }
+}
+
+impl MorphySerde for Custom {
+    fn encode<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
+        write!(f, "c:")?;
+        for ch in escape(&self.unit) {
+            write!(f, "{}", ch)?;
+        }
+        write!(f, ",{:x},", self.tag_idx)?;
+        write!(f, "{},", if self.word_lower.is_known() { 'd' } else { 'f' })?;
+        for ch in escape(self.word_lower.word()) {
+            write!(f, "{}", ch)?;
+        }
+        if let Some(ref normal_form) = self.normal_form {
+            write!(f, ",")?;
+            for ch in escape(normal_form) {
+                write!(f, "{}", ch)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// ```
+    /// use rsmorphy::container::abc::*;
+    /// use rsmorphy::container::{Custom, WordStruct};
+    ///
+    /// let sku = Custom::new("sku", WordStruct::unknown("ab-12,3"), 1);
+    /// assert_eq!(sku.encoded(), r"c:sku,1,f,ab-12\,3");
+    /// assert_eq!(Custom::decode(r"c:sku,1,f,ab-12\,3"), Ok(("", sku)));
+    ///
+    /// let drug = Custom::new("med", WordStruct::known("аспирина"), 0xa)
+    ///     .with_normal_form("аспирин");
+    /// assert_eq!(drug.encoded(), r"c:med,a,d,аспирина,аспирин");
+    /// assert_eq!(Custom::decode(r"c:med,a,d,аспирина,аспирин;hp:-то"), Ok((";hp:-то", drug)));
+    /// ```
+    fn decode(s: &str) -> Result<(&str, Self), DecodeError> {
+        let s = follow_str(s, "c").map_err(|_| DecodeError::UnknownPartType)?;
+        let (s, unit) = take_escaped_until(follow_str(s, ":")?, &[','])?;
+        let (s, tag_idx) = take_str_while_char(follow_str(s, ",")?, is_hex_digit)
+            .and_then(parse_hex_int::<u16>)?;
+        let s = follow_str(s, ",")?;
+        let (s, is_known) = follow_str(s, "d")
+            .map(|s| (s, true))
+            .or_else(|_| follow_str(s, "f").map(|s| (s, false)))
+            .map_err(|_| DecodeError::UnknownPartType)?;
+        let (s, word) = take_escaped_until(follow_str(s, ",")?, &[',', ';'])?;
+        let (s, normal_form) = match follow_str(s, ",") {
+            Ok(s) => take_escaped_until(s, &[';']).map(|(s, nf)| (s, Some(nf)))?,
+            Err(_) => (s, None),
+        };
+        let word_lower = WordStruct::new(unescape(word).collect::<String>(), is_known);
+        Ok((
+            s,
+            Custom {
+                unit: unescape(unit).collect(),
+                word_lower,
+                normal_form: normal_form.map(|nf| unescape(nf).collect()),
+                tag_idx,
+            },
+        ))
+    }
+}
@@ -1,5 +1,7 @@
 use std::num::{ParseFloatError, ParseIntError};
 
+use crate::language::Language;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum DecodeError {
     /// A decoder expects more
@@ -12,6 +14,8 @@ pub enum DecodeError {
     ParseIntError(ParseIntError),
     /// The number decoder failed to parse a float
     ParseFloatError(ParseFloatError),
+    /// The id is of another language than the dictionary
+    WrongLanguage(Language),
 }
 
 impl From<ParseIntError> for DecodeError {
@@ -42,3 +42,23 @@ pub fn take_str_while_char_is(s: &str, chr: char) -> Result<(&str, &str), Decode
 pub fn take_str_until_char_is(s: &str, chr: char) -> Result<(&str, &str), DecodeError> {
     take_str_until(s, |ch| ch == chr)
 }
+
+/// Takes an escaped string until one of the unescaped `stop` chars.
+/// The result is left escaped; see `unescape`.
+pub fn take_escaped_until<'s>(
+    s: &'s str,
+    stop: &[char],
+) -> Result<(&'s str, &'s str), DecodeError> {
+    let mut esc = false;
+    take_str_until(s, |ch| match (esc, ch) {
+        (true, _) => {
+            esc = false;
+            false
+        }
+        (false, '\\') => {
+            esc = true;
+            false
+        }
+        (false, ch) => stop.contains(&ch),
+    })
+}
@@ -1,4 +1,6 @@
 use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::error::Error;
 use std::fmt;
 
 use crate::analyzer::MorphAnalyzer;
@@ -7,28 +9,79 @@ use crate::container::decode::*;
 use crate::container::paradigm::ParadigmId;
 use crate::container::stack::StackParticle;
 use crate::container::{Score, Seen};
-use crate::opencorpora::{GrammemeSet, OpencorporaTagReg};
+use crate::language::Language;
+use crate::opencorpora::kind::Flag;
+use crate::opencorpora::{Grammeme, GrammemeSet, OpencorporaTagReg};
 
 pub type Lexeme = Vec<Lex>;
 
+/// A form found by `Lex::inflect_with_fallbacks`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Inflected {
+    pub lex: Lex,
+    /// Required grammemes which are replaced, sorted by the required one.
+    pub fallbacks: Vec<Fallback>,
+}
+
+/// A required grammeme and the one taken for it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Fallback {
+    pub required: Grammeme,
+    pub used: Grammeme,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InflectError {
+    /// The required grammeme is unknown to the dictionary.
+    UnknownGrammeme(Grammeme),
+    /// No form of the lexeme has all required grammemes; the closest form
+    /// lacks the `missing` ones.
+    Unsatisfied {
+        missing: Vec<Grammeme>,
+        closest: Box<Lex>,
+    },
+}
+
+impl fmt::Display for InflectError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InflectError::UnknownGrammeme(grammeme) => write!(f, "unknown grammeme {}", grammeme),
+            InflectError::Unsatisfied { missing, .. } => {
+                let missing: Vec<&str> = missing.iter().map(Grammeme::as_str).collect();
+                write!(f, "no form of the lexeme has {}", missing.join(","))
+            }
+        }
+    }
+}
+
+impl Error for InflectError {}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Lex {
+    pub lang: Language,
     pub stack: StackParticle,
 }
 
 impl Lex {
-    pub fn from_id<S>(_morph: &MorphAnalyzer, id: S) -> Result<Self, DecodeError>
+    /// Decodes the id of a lex of the analyzer's dictionary; ids of other
+    /// languages are rejected.
+    pub fn from_id<S>(morph: &MorphAnalyzer, id: S) -> Result<Self, DecodeError>
     where
         S: AsRef<str>,
     {
-        Self::decode(id.as_ref()).map(|(_, lex)| lex)
+        let (_, lex) = Self::decode(id.as_ref())?;
+        if lex.lang != morph.dict.language {
+            return Err(DecodeError::WrongLanguage(lex.lang));
+        }
+        Ok(lex)
     }
 
-    pub fn from_stack<S>(_morph: &MorphAnalyzer, stack: S) -> Self
+    pub fn from_stack<S>(morph: &MorphAnalyzer, stack: S) -> Self
     where
         S: Into<StackParticle>,
     {
         Lex {
+            lang: morph.dict.language,
             stack: stack.into(),
         }
     }
@@ -49,26 +102,211 @@ impl Lex {
     }
 
     pub fn get_plural(&self, morph: &MorphAnalyzer, num: usize) -> Option<Lex> {
-        self.inflect(morph, &self.get_tag(morph).numeral_agreement_grammemes(num))
+        let tag = self.get_tag(morph);
+        self.inflect(morph, &tag.numeral_agreement_grammemes_in(self.lang, num))
     }
 
+    /// The form of the lexeme closest to the required grammemes. Forms with
+    /// all of them come first, with rare cases the lexeme lacks replaced
+    /// (see `inflect_with_fallbacks`); if there are none, the form sharing
+    /// the most grammemes is taken anyway, so "ножницы" stays plural.
+    /// Of equally close forms, the one `MorphAnalyzer::variant_policy` ranks
+    /// higher is taken, so "рыба" goes to "рыбой" rather than "рыбою".
     pub fn inflect(&self, morph: &MorphAnalyzer, required: &GrammemeSet) -> Option<Lex> {
-        let new_grammemes = self.get_tag(morph).prepare_required(morph, required);
+        self.inflect_with_fallbacks(morph, required)
+            .map(|inflected| inflected.lex)
+            .or_else(|| self.closest_form(morph, required, false))
+    }
+
+    /// The closest form with all the required grammemes, telling which ones
+    /// were replaced: if no form of the lexeme has all of them, rare cases
+    /// fall back along `Dictionary::grammeme_fallback`, like "loc2" to "loct".
+    /// `None` if no form fits even then.
+    ///
+    /// ```
+    /// use rsmorphy::container::Fallback;
+    /// use rsmorphy::prelude::*;
+    ///
+    /// let morph = MorphAnalyzer::from_file(rsmorphy::dict_ru::DICT_PATH);
+    /// let loc2 = GrammemeSet::new("loc2");
+    /// let les = morph.parse("лес")[0].lex.inflect_with_fallbacks(&morph, &loc2).unwrap();
+    /// assert_eq!((les.lex.get_word().as_ref(), les.fallbacks.len()), ("лесу", 0));
+    /// let kniga = morph.parse("книга")[0].lex.inflect_with_fallbacks(&morph, &loc2).unwrap();
+    /// assert_eq!(kniga.lex.get_word(), "книге");
+    /// assert_eq!(
+    ///     kniga.fallbacks,
+    ///     vec![Fallback { required: Grammeme::new("loc2"), used: Grammeme::new("loct") }]
+    /// );
+    /// ```
+    pub fn inflect_with_fallbacks(
+        &self,
+        morph: &MorphAnalyzer,
+        required: &GrammemeSet,
+    ) -> Option<Inflected> {
+        let mut required = required.clone();
+        let mut fallbacks: Vec<Fallback> = Vec::new();
+        // Guards against cycles of fallbacks.
+        for _ in 0..morph.dict.grammeme_metas.len() {
+            if let Some(lex) = self.closest_form(morph, &required, true) {
+                fallbacks.sort_by(|a, b| a.required.as_str().cmp(b.required.as_str()));
+                return Some(Inflected { lex, fallbacks });
+            }
+            let replaced: Vec<(Grammeme, Grammeme)> = required
+                .set
+                .iter()
+                .filter_map(|grammeme| {
+                    let fallback = morph.dict.grammeme_fallback(grammeme)?;
+                    Some((grammeme.clone(), fallback))
+                })
+                .collect();
+            if replaced.is_empty() {
+                return None;
+            }
+            for (grammeme, used) in replaced {
+                required.set.remove(&grammeme);
+                required.set.insert(used.clone());
+                // A chain of fallbacks is reported as a single one.
+                let previous = fallbacks.iter_mut().find(|f| f.used == grammeme);
+                match previous {
+                    Some(fallback) => fallback.used = used,
+                    None => fallbacks.push(Fallback {
+                        required: grammeme,
+                        used,
+                    }),
+                }
+            }
+        }
+        None
+    }
+
+    /// The closest form, of the ones with all the required grammemes if
+    /// `complete`; ties are broken by `MorphAnalyzer::variant_policy`.
+    fn closest_form(
+        &self,
+        morph: &MorphAnalyzer,
+        required: &GrammemeSet,
+        complete: bool,
+    ) -> Option<Lex> {
+        let tag = self.get_tag(morph);
+        let policy = morph.variant_policy;
+        let avoided = avoided_grammemes(morph, required);
+        let bits = morph.dict.grammeme_bits(required).and_then(|required| {
+            let new_grammemes = tag.prepare_required_bits(morph, required)?;
+            let avoided = morph.dict.grammeme_bits(&avoided)?;
+            Some((required, new_grammemes - avoided))
+        });
+        if let Some((required_bits, new_grammemes)) = bits {
+            return self
+                .iter_lexeme(morph)
+                .filter_map(|lex| {
+                    let tag = lex.get_tag(morph);
+                    let hsl = match morph.dict.tag_bits(tag) {
+                        Some(bits) if !complete || required_bits.is_subset(bits) => {
+                            (bits & new_grammemes).len()
+                        }
+                        Some(_) => return None,
+                        // Tags with grammemes unknown to the dictionary.
+                        None if !complete || required.set.is_subset(&tag.grammemes.set) => 0,
+                        None => return None,
+                    };
+                    let rank = policy.rank(tag.flags);
+                    Some((lex, hsl, rank))
+                })
+                .max_by_key(|&(_, hsl, rank)| (hsl, rank))
+                .map(|(lex, _, _)| lex);
+        }
+
+        let new_grammemes = &tag.prepare_required(morph, required).set - &avoided.set;
         self.iter_lexeme(morph)
+            .filter_map(|lex| {
+                let tag = lex.get_tag(morph);
+                if complete && !required.set.is_subset(&tag.grammemes.set) {
+                    return None;
+                }
+                let hsl = tag.grammemes.set.intersection(&new_grammemes).count();
+                let rank = policy.rank(tag.flags);
+                Some((lex, hsl, rank))
+            })
+            .max_by_key(|&(_, hsl, rank)| (hsl, rank))
+            .map(|(lex, _, _)| lex)
+    }
+
+    /// Like `inflect_with_fallbacks` with no fallbacks, but tells which
+    /// required grammemes are missing when no form has all of them.
+    ///
+    /// ```
+    /// use rsmorphy::container::InflectError;
+    /// use rsmorphy::prelude::*;
+    ///
+    /// let morph = MorphAnalyzer::from_file(rsmorphy::dict_ru::DICT_PATH);
+    /// let lex = &morph.parse("ножницы")[0].lex;
+    /// let plur = lex.inflect_strict(&morph, &GrammemeSet::new("plur,gent")).unwrap();
+    /// assert_eq!(plur.get_word(), "ножниц");
+    /// let sing = GrammemeSet::new("sing,gent");
+    /// // The lenient `inflect` gives a plural form anyway.
+    /// assert_eq!(lex.inflect(&morph, &sing).unwrap().get_word(), "ножниц");
+    /// match lex.inflect_strict(&morph, &sing) {
+    ///     Err(InflectError::Unsatisfied { missing, closest }) => {
+    ///         assert_eq!(missing, vec![Grammeme::new("sing")]);
+    ///         assert_eq!(closest.get_word(), "ножниц");
+    ///     }
+    ///     result => panic!("{:?}", result),
+    /// }
+    /// ```
+    pub fn inflect_strict(
+        &self,
+        morph: &MorphAnalyzer,
+        required: &GrammemeSet,
+    ) -> Result<Lex, InflectError> {
+        if let Some(grammeme) = required
+            .set
+            .iter()
+            .find(|grammeme| !morph.dict.grammeme_metas.contains_key(grammeme))
+        {
+            return Err(InflectError::UnknownGrammeme(grammeme.clone()));
+        }
+        let policy = morph.variant_policy;
+        let new_grammemes = &self.get_tag(morph).prepare_required(morph, required).set
+            - &avoided_grammemes(morph, required).set;
+        // The form with the fewest missing grammemes, then the closest one.
+        let closest = self
+            .iter_lexeme(morph)
             .map(|lex| {
-                let hsl = lex
-                    .get_tag(morph)
-                    .grammemes
-                    .set
-                    .intersection(&new_grammemes.set)
-                    .count();
-                (lex, hsl)
+                let tag = lex.get_tag(morph);
+                let missing = required.set.difference(&tag.grammemes.set).count();
+                let hsl = tag.grammemes.set.intersection(&new_grammemes).count();
+                (lex, missing, hsl, policy.rank(tag.flags))
             })
-            .max_by_key(|&(_, hsl)| hsl)
-            .map(|(lex, _)| lex)
+            .max_by_key(|&(_, missing, hsl, rank)| (Reverse(missing), hsl, rank))
+            .map_or_else(|| self.clone(), |(lex, _, _, _)| lex);
+
+        let grammemes = &closest.get_tag(morph).grammemes.set;
+        let mut missing: Vec<Grammeme> = required.set.difference(grammemes).cloned().collect();
+        if missing.is_empty() {
+            return Ok(closest);
+        }
+        missing.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        Err(InflectError::Unsatisfied {
+            missing,
+            closest: Box::new(closest),
+        })
     }
 }
 
+/// Grammemes avoided by the variant policy, unless required; they aren't
+/// carried over from the form being inflected, so "рыбою" goes to "рыбами".
+fn avoided_grammemes(morph: &MorphAnalyzer, required: &GrammemeSet) -> GrammemeSet {
+    let set = Flag::ALL
+        .iter()
+        .cloned()
+        .filter(|&flag| morph.variant_policy.is_avoided(flag))
+        .map(Grammeme::from)
+        .filter(|grammeme| morph.dict.grammeme_metas.contains_key(grammeme))
+        .filter(|grammeme| !required.set.contains(grammeme))
+        .collect();
+    GrammemeSet { set }
+}
+
 impl Source for Lex {
     fn score(&self) -> Score {
         self.stack.score()
@@ -123,13 +361,14 @@ impl fmt::Display for Lex {
 
 impl MorphySerde for Lex {
     fn encode<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
-        write!(f, "ru:")?;
+        write!(f, "{}:", self.lang.code())?;
         self.stack.encode(f)
     }
 
     fn decode(s: &str) -> Result<(&str, Self), DecodeError> {
-        let s = follow_str(s, "ru").map_err(|_| DecodeError::UnknownPartType)?;
+        let (s, code) = take_str_until_char_is(s, ':')?;
+        let lang = Language::from_code(code).ok_or(DecodeError::UnknownPartType)?;
         let (s, stack) = StackParticle::decode(follow_str(s, ":")?)?;
-        Ok((s, Lex { stack }))
+        Ok((s, Lex { lang, stack }))
     }
 }
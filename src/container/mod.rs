@@ -6,29 +6,37 @@ pub mod dict;
 pub mod paradigm;
 
 pub mod affix;
+pub mod custom;
 pub mod ha;
 pub mod hyphen;
 pub mod initials;
+pub mod repaired;
 pub mod shape;
 pub mod unknown;
 
 pub mod lex;
 pub mod parsed;
+pub mod pymorphy2;
 pub mod score;
 pub mod seen;
 pub mod stack;
+pub mod view;
 
 pub mod decode;
 
 pub use self::affix::{Affix, AffixKind};
+pub use self::custom::Custom;
 pub use self::dict::Dictionary;
 pub use self::ha::HyphenAdverb;
 pub use self::hyphen::HyphenSeparatedParticle;
 pub use self::initials::{Initials, InitialsKind};
-pub use self::lex::Lex;
+pub use self::lex::{Fallback, InflectError, Inflected, Lex};
 pub use self::parsed::{ParseResult, Parsed};
+pub use self::pymorphy2::Pymorphy2Parse;
+pub use self::repaired::Repaired;
 pub use self::score::Score;
 pub use self::seen::{Seen, SeenSet};
 pub use self::shape::{ShapeKind, Shaped};
 pub use self::unknown::Unknown;
+pub use self::view::LexView;
 pub use self::word::WordStruct;
@@ -0,0 +1,169 @@
+//! Parses in the shape pymorphy2 gives them, to compare the results
+//! of both analyzers.
+
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::analyzer::MorphAnalyzer;
+use crate::container::abc::*;
+use crate::container::stack::{StackAffix, StackHyphenated, StackParticle, StackSource};
+use crate::container::{AffixKind, Dictionary, InitialsKind, Parsed, ShapeKind};
+
+type Tuple = (String, String, String, f64, Vec<Value>);
+
+/// pymorphy2's `Parse` tuple: `(word, tag, normal_form, score, methods_stack)`.
+///
+/// It's serialized as a JSON array, and methods of the stack as arrays
+/// of the pymorphy2 unit name followed by its arguments, like
+/// `["DictionaryAnalyzer", "стали", 904, 4]`. Except for units pymorphy2
+/// lacks, like `HomoglyphAnalyzer`, the same is made of a pymorphy2 parse `p`
+/// in Python with
+///
+/// ```python
+/// def method(m):
+///     return [type(m[0]).__name__] + [
+///         [method(a) for a in arg] if isinstance(arg, tuple) else arg for arg in m[1:]
+///     ]
+///
+/// [p.word, str(p.tag), p.normal_form, p.score, [method(m) for m in p.methods_stack]]
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "Tuple", into = "Tuple")]
+pub struct Pymorphy2Parse {
+    pub word: String,
+    /// The tag in the opencorpora-int tagset, as pymorphy2 prints it.
+    pub tag: String,
+    pub normal_form: String,
+    pub score: f64,
+    /// Innermost methods first, like in pymorphy2.
+    pub methods_stack: Vec<Value>,
+}
+
+impl From<Tuple> for Pymorphy2Parse {
+    fn from((word, tag, normal_form, score, methods_stack): Tuple) -> Self {
+        Pymorphy2Parse {
+            word,
+            tag,
+            normal_form,
+            score,
+            methods_stack,
+        }
+    }
+}
+
+impl From<Pymorphy2Parse> for Tuple {
+    fn from(parse: Pymorphy2Parse) -> Self {
+        (
+            parse.word,
+            parse.tag,
+            parse.normal_form,
+            parse.score,
+            parse.methods_stack,
+        )
+    }
+}
+
+impl Parsed {
+    /// ```
+    /// use rsmorphy::prelude::*;
+    ///
+    /// let morph = MorphAnalyzer::from_file(rsmorphy::dict_ru::DICT_PATH);
+    /// let parse = morph.parse("стали")[0].to_pymorphy2(&morph);
+    /// assert_eq!(
+    ///     serde_json::to_string(&parse.methods_stack).unwrap(),
+    ///     r#"[["DictionaryAnalyzer","стали",904,4]]"#
+    /// );
+    /// ```
+    pub fn to_pymorphy2(&self, morph: &MorphAnalyzer) -> Pymorphy2Parse {
+        let lex = &self.lex;
+        Pymorphy2Parse {
+            word: lex.get_word().into_owned(),
+            tag: lex.get_tag(morph).string.clone(),
+            normal_form: lex.get_normal_form(morph).into_owned(),
+            score: self.score.value(),
+            methods_stack: particle_methods(&lex.stack),
+        }
+    }
+}
+
+fn particle_methods(stack: &StackParticle) -> Vec<Value> {
+    let mut methods = hyphenated_methods(&stack.stack);
+    if let Some(ref particle) = stack.particle {
+        methods.push(json!([
+            "HyphenSeparatedParticleAnalyzer",
+            particle.particle
+        ]));
+    }
+    methods
+}
+
+fn hyphenated_methods(stack: &StackHyphenated) -> Vec<Value> {
+    match stack.right {
+        None => affix_methods(&stack.left),
+        Some(ref right) => vec![json!([
+            "HyphenatedWordsAnalyzer",
+            affix_methods(&stack.left),
+            affix_methods(right)
+        ])],
+    }
+}
+
+fn affix_methods(stack: &StackAffix) -> Vec<Value> {
+    // pymorphy2 names the source of the parses guessed by a known suffix
+    // `FakeDictionary`.
+    let dictionary = match stack.affix {
+        Some(ref affix) if affix.kind == AffixKind::KnownSuffix => "FakeDictionary",
+        _ => "DictionaryAnalyzer",
+    };
+    let mut methods = vec![source_method(&stack.stack, dictionary)];
+    if let Some(ref affix) = stack.affix {
+        let unit = match affix.kind {
+            AffixKind::KnownSuffix => "KnownSuffixAnalyzer",
+            AffixKind::KnownPrefix => "KnownPrefixAnalyzer",
+            AffixKind::UnknownPrefix => "UnknownPrefixAnalyzer",
+        };
+        methods.push(json!([unit, affix.part]));
+    }
+    methods
+}
+
+/// `dictionary` is the name of the unit for dictionary sources.
+fn source_method(source: &StackSource, dictionary: &str) -> Value {
+    let unit = match source {
+        StackSource::Dictionary(source) => {
+            return dictionary_method(source, dictionary);
+        }
+        // pymorphy2 has no repair of Latin look-alikes, so these parses
+        // are told apart from the dictionary ones.
+        StackSource::Repaired(source) => {
+            return json!([
+                "HomoglyphAnalyzer",
+                source.original,
+                [dictionary_method(&source.source, dictionary)]
+            ]);
+        }
+        StackSource::HyphenAdverb(_) => "HyphenAdverbAnalyzer",
+        StackSource::Initials(source) => match source.kind {
+            InitialsKind::FirstName => "AbbreviatedFirstNameAnalyzer",
+            InitialsKind::Patronym => "AbbreviatedPatronymicAnalyzer",
+        },
+        StackSource::Shaped(source) => match source.kind() {
+            ShapeKind::Number { .. } => "NumberAnalyzer",
+            ShapeKind::RomanNumber => "RomanNumberAnalyzer",
+            ShapeKind::Latin => "LatinAnalyzer",
+            ShapeKind::Punctuation => "PunctuationAnalyzer",
+        },
+        StackSource::Unknown(_) => "UnknAnalyzer",
+        StackSource::Custom(source) => &source.unit,
+    };
+    json!([unit, source.get_word()])
+}
+
+fn dictionary_method(source: &Dictionary, unit: &str) -> Value {
+    json!([
+        unit,
+        source.word_lower().word(),
+        source.para_id().value(),
+        source.idx().value()
+    ])
+}
@@ -0,0 +1,111 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::analyzer::MorphAnalyzer;
+use crate::container::abc::*;
+use crate::container::decode::*;
+use crate::container::paradigm::ParadigmId;
+use crate::container::{Dictionary, Lex, Score};
+use crate::opencorpora::tag::OpencorporaTagReg;
+
+/// A dictionary word found after replacing Latin look-alike letters
+/// with Cyrillic ones, e.g. "задaч" (with a Latin "a") -> "задач".
+///
+/// The word is the repaired one; `original` is the word exactly as given.
+/// Other forms of the lexeme are plain dictionary words.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Repaired {
+    pub original: String,
+    pub source: Dictionary,
+}
+
+impl Repaired {
+    pub fn new<O>(original: O, source: Dictionary) -> Self
+    where
+        O: Into<String>,
+    {
+        let original = original.into();
+        Repaired { original, source }
+    }
+
+    pub fn iter_lexeme<'s: 'i, 'm: 'i, 'i>(
+        &'s self,
+        morph: &'m MorphAnalyzer,
+    ) -> impl Iterator<Item = Lex> + 'i {
+        self.source.iter_lexeme(morph)
+    }
+}
+
+impl Source for Repaired {
+    fn score(&self) -> Score {
+        self.source.score()
+    }
+
+    fn is_lemma(&self) -> bool {
+        self.source.is_lemma()
+    }
+
+    fn is_known(&self) -> bool {
+        self.source.is_known()
+    }
+
+    fn get_word(&self) -> Cow<'_, str> {
+        self.source.get_word()
+    }
+
+    fn get_normal_form(&self, morph: &MorphAnalyzer) -> Cow<'_, str> {
+        self.source.get_normal_form(morph)
+    }
+
+    fn get_tag<'m>(&self, morph: &'m MorphAnalyzer) -> &'m OpencorporaTagReg {
+        self.source.get_tag(morph)
+    }
+
+    fn try_get_para_id(&self) -> Option<ParadigmId> {
+        self.source.try_get_para_id()
+    }
+
+    fn write_word<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
+        self.source.write_word(f)
+    }
+
+    fn write_normal_form<W: fmt::Write>(&self, f: &mut W, morph: &MorphAnalyzer) -> fmt::Result {
+        self.source.write_normal_form(f, morph)
+    }
+
+    fn get_lexeme(&self, morph: &MorphAnalyzer) -> Vec<Lex> {
+        self.iter_lexeme(morph).collect()
+    }
+
+    fn get_lemma(&self, morph: &MorphAnalyzer) -> Lex {
+        self.iter_lexeme(morph).next().unwrap()
+    }
+}
+
+impl MorphySerde for Repaired {
+    fn encode<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
+        write!(f, "r:")?;
+        for ch in escape(&self.original) {
+            write!(f, "{}", ch)?;
+        }
+        write!(f, ",")?;
+        self.source.encode(f)
+    }
+
+    /// ```
+    /// use rsmorphy::container::abc::*;
+    /// use rsmorphy::container::{Dictionary, Repaired, WordStruct};
+    ///
+    /// let source = Dictionary::new(WordStruct::known("задач"), 0x5_u16, 0x8_u16);
+    /// let repaired = Repaired::new("задaч", source);
+    /// assert_eq!(repaired.encoded(), "r:задaч,d:задач,5,8");
+    /// assert_eq!(Repaired::decode("r:задaч,d:задач,5,8;hp:-то"), Ok((";hp:-то", repaired)));
+    /// ```
+    fn decode(s: &str) -> Result<(&str, Self), DecodeError> {
+        let s = follow_str(s, "r").map_err(|_| DecodeError::UnknownPartType)?;
+        let (s, original) = take_escaped_until(follow_str(s, ":")?, &[','])?;
+        let (s, source) = Dictionary::decode(follow_str(s, ",")?)?;
+        let original = unescape(original).collect::<String>();
+        Ok((s, Repaired { original, source }))
+    }
+}
@@ -35,6 +35,10 @@ impl Shaped {
         Shaped { word, kind }
     }
 
+    pub fn kind(&self) -> ShapeKind {
+        self.kind
+    }
+
     pub fn number<S>(word: S, is_float: bool) -> Self
     where
         S: Into<String>,
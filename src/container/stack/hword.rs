@@ -3,9 +3,11 @@ use std::fmt;
 
 use crate::analyzer::MorphAnalyzer;
 use crate::container::abc::*;
+use crate::container::decode::*;
 use crate::container::paradigm::ParadigmId;
 use crate::container::stack::{StackAffix, StackSource};
 use crate::container::{Lex, Score};
+use crate::opencorpora::kind::{Case, Number, PartOfSpeach, Person, Tense};
 use crate::opencorpora::OpencorporaTagReg;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -23,13 +25,90 @@ impl StackHyphenated {
         StackHyphenated { left, right }
     }
 
+    /// Whether only the right part is inflected, like in "интернет-магазин";
+    /// the left part is kept as an `Unknown` source then.
+    pub fn is_fixed_left(&self) -> bool {
+        self.right.is_some() && self.left.affix.is_none() && self.left.stack.as_unknown().is_some()
+    }
+
     pub fn iter_lexeme<'s: 'i, 'm: 'i, 'i>(
         &'s self,
         morph: &'m MorphAnalyzer,
-    ) -> impl Iterator<Item = Lex> + 'i {
-        self.left.iter_lexeme(morph).map(move |lex: Lex| {
-            Lex::from_stack(morph, StackHyphenated::new(lex.stack.stack.left, None))
-        })
+    ) -> Box<dyn Iterator<Item = Lex> + 'i> {
+        let right = match self.right {
+            None => {
+                return Box::new(self.left.iter_lexeme(morph).map(move |lex: Lex| {
+                    Lex::from_stack(morph, StackHyphenated::new(lex.stack.stack.left, None))
+                }))
+            }
+            Some(ref right) => right,
+        };
+        if self.is_fixed_left() {
+            return Box::new(right.iter_lexeme(morph).map(move |lex: Lex| {
+                let right = lex.stack.stack.left;
+                Lex::from_stack(morph, StackHyphenated::new(self.left.clone(), right))
+            }));
+        }
+        // Every form of the right part is paired with the most similar form
+        // of the left part.
+        let left_lexeme: Vec<StackAffix> = self
+            .left
+            .iter_lexeme(morph)
+            .map(|lex| lex.stack.stack.left)
+            .collect();
+        Box::new(right.iter_lexeme(morph).filter_map(move |lex: Lex| {
+            let right = lex.stack.stack.left;
+            let features = similarity_features(right.get_tag(morph));
+            let left = left_lexeme
+                .iter()
+                .min_by_key(|left| features.distance(&similarity_features(left.get_tag(morph))))?;
+            Some(Lex::from_stack(
+                morph,
+                StackHyphenated::new(left.clone(), right),
+            ))
+        }))
+    }
+}
+
+/// Grammemes which must agree in both parts of a hyphenated word
+/// like "человек-гора".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SimilarityFeatures {
+    pub pos: Option<PartOfSpeach>,
+    pub number: Option<Number>,
+    pub case: Option<Case>,
+    pub person: Option<Person>,
+    pub tense: Option<Tense>,
+}
+
+impl SimilarityFeatures {
+    /// The number of disagreeing features.
+    pub fn distance(&self, other: &Self) -> usize {
+        [
+            self.pos != other.pos,
+            self.number != other.number,
+            self.case != other.case,
+            self.person != other.person,
+            self.tense != other.tense,
+        ]
+        .iter()
+        .filter(|&&differs| differs)
+        .count()
+    }
+}
+
+pub fn similarity_features(tag: &OpencorporaTagReg) -> SimilarityFeatures {
+    let case = tag.case.map(|case| match case {
+        Case::Gen1 => Case::Gent,
+        Case::Loc1 => Case::Loct,
+        case => case,
+    });
+    SimilarityFeatures {
+        pos: tag.pos,
+        number: tag.number,
+        case,
+        person: tag.person,
+        tense: tag.tense,
     }
 }
 
@@ -50,12 +129,16 @@ impl From<StackSource> for StackHyphenated {
 
 impl Source for StackHyphenated {
     fn score(&self) -> Score {
-        unimplemented!()
+        match self.right {
+            Some(ref right) if self.is_fixed_left() => right.score(),
+            _ => self.left.score(),
+        }
     }
 
     fn is_lemma(&self) -> bool {
         match self.right {
             None => self.left.is_lemma(),
+            Some(ref right) if self.is_fixed_left() => right.is_lemma(),
             Some(ref right) => self.left.is_lemma() && right.is_lemma(),
         }
     }
@@ -63,6 +146,7 @@ impl Source for StackHyphenated {
     fn is_known(&self) -> bool {
         match self.right {
             None => self.left.is_known(),
+            Some(ref right) if self.is_fixed_left() => right.is_known(),
             Some(ref right) => self.left.is_known() && right.is_known(),
         }
     }
@@ -88,21 +172,24 @@ impl Source for StackHyphenated {
 
     fn get_tag<'m>(&self, morph: &'m MorphAnalyzer) -> &'m OpencorporaTagReg {
         match self.right {
-            None => self.left.get_tag(morph),
-            Some(_) => unimplemented!(),
+            Some(ref right) if self.is_fixed_left() => right.get_tag(morph),
+            _ => self.left.get_tag(morph),
         }
     }
 
+    /// Like in pymorphy2, parses of a hyphenated word are told apart by
+    /// the right part, so a left parse may be paired with several right ones.
     fn try_get_para_id(&self) -> Option<ParadigmId> {
         match self.right {
+            Some(ref right) => right.try_get_para_id(),
             None => self.left.try_get_para_id(),
-            Some(_) => unimplemented!(),
         }
     }
 
     fn write_word<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
         self.left.write_word(f)?;
         if let Some(ref right) = self.right {
+            write!(f, "-")?;
             right.write_word(f)?;
         }
         Ok(())
@@ -111,6 +198,7 @@ impl Source for StackHyphenated {
     fn write_normal_form<W: fmt::Write>(&self, f: &mut W, morph: &MorphAnalyzer) -> fmt::Result {
         self.left.write_normal_form(f, morph)?;
         if let Some(ref right) = self.right {
+            write!(f, "-")?;
             right.write_normal_form(f, morph)?;
         }
         Ok(())
@@ -138,30 +226,29 @@ impl MorphySerde for StackHyphenated {
         }
     }
 
+    /// ```
+    /// use rsmorphy::container::abc::*;
+    /// use rsmorphy::container::stack::{StackAffix, StackHyphenated, StackSource};
+    /// use rsmorphy::container::{Dictionary, Unknown, WordStruct};
+    ///
+    /// let left = StackSource::from(Unknown::new("интернет"));
+    /// let right = Dictionary::new(WordStruct::new("магазина", true), 0x1f_u16, 1_u16);
+    /// let right = StackSource::from(right);
+    /// assert_eq!(
+    ///     StackHyphenated::decode(r"hw:u:интернет;-d:магазина,1f,1"),
+    ///     Ok((
+    ///         "",
+    ///         StackHyphenated::new(StackAffix::from(left), StackAffix::from(right))
+    ///     ))
+    /// );
+    /// ```
     fn decode(s: &str) -> Result<(&str, Self), DecodeError> {
-        let (s, stack) = StackAffix::decode(s)?;
-        let result = (s, StackHyphenated::new(stack, None));
-        // TODO
-        //        if !s.is_empty() {
-        //            match (|s| {
-        //                let s = follow_str(s, ";")?;
-        //                let s = follow_str(s, "hw").map_err(|e| match e {
-        //                    DecodeError::DoesntMatch => DecodeError::UnknownPartType,
-        //                    _ => e,
-        //                })?;
-        //                let (s, word) = take_str_until_char_is(follow_str(s, ":")?,
-        // ';')?;                Ok((s, HyphenSeparatedParticle {
-        //                    particle: word.to_string(),
-        //                }))
-        //            })(s) {
-        //                Err(DecodeError::UnknownPartType) => (),
-        //                Err(e) => Err(e)?,
-        //                Ok((s, particle)) => {
-        //                    result.0 = s;
-        //                    result.1.particle = Some(particle);
-        //                },
-        //            };
-        //        }
-        Ok(result)
+        if !s.starts_with("hw:") {
+            let (s, stack) = StackAffix::decode(s)?;
+            return Ok((s, StackHyphenated::new(stack, None)));
+        }
+        let (s, left) = StackAffix::decode(follow_str(s, "hw:")?)?;
+        let (s, right) = StackAffix::decode(follow_str(s, ";-")?)?;
+        Ok((s, StackHyphenated::new(left, right)))
     }
 }
@@ -5,16 +5,20 @@ use self::StackSource::*;
 use crate::analyzer::MorphAnalyzer;
 use crate::container::abc::*;
 use crate::container::paradigm::ParadigmId;
-use crate::container::{Dictionary, HyphenAdverb, Initials, Lex, Score, Shaped, Unknown};
+use crate::container::{
+    Custom, Dictionary, HyphenAdverb, Initials, Lex, Repaired, Score, Shaped, Unknown,
+};
 use crate::opencorpora::OpencorporaTagReg;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum StackSource {
     Dictionary(Dictionary),
+    Repaired(Repaired),
     HyphenAdverb(HyphenAdverb),
     Initials(Initials),
     Shaped(Shaped),
     Unknown(Unknown),
+    Custom(Custom),
 }
 
 impl StackSource {
@@ -32,6 +36,13 @@ impl StackSource {
         }
     }
 
+    pub fn as_repaired(&self) -> Option<&Repaired> {
+        match self {
+            Repaired(source) => Some(source),
+            _ => None,
+        }
+    }
+
     pub fn as_hyphen_adverb(&self) -> Option<&HyphenAdverb> {
         match self {
             HyphenAdverb(source) => Some(source),
@@ -60,16 +71,25 @@ impl StackSource {
         }
     }
 
+    pub fn as_custom(&self) -> Option<&Custom> {
+        match self {
+            Custom(source) => Some(source),
+            _ => None,
+        }
+    }
+
     pub fn iter_lexeme<'s: 'i, 'm: 'i, 'i>(
         &'s self,
         morph: &'m MorphAnalyzer,
     ) -> Box<dyn Iterator<Item = Lex> + 'i> {
         match self {
             Dictionary(source) => Box::new(source.iter_lexeme(morph)),
+            Repaired(source) => Box::new(source.iter_lexeme(morph)),
             HyphenAdverb(source) => Box::new(source.iter_lexeme(morph)),
             Initials(source) => Box::new(source.iter_lexeme(morph)),
             Shaped(source) => Box::new(source.iter_lexeme(morph)),
             Unknown(source) => Box::new(source.iter_lexeme(morph)),
+            Custom(source) => Box::new(source.iter_lexeme(morph)),
         }
     }
 
@@ -79,10 +99,12 @@ impl StackSource {
                 true => "Словарное слово",
                 false => "Неизвестное слово",
             },
+            Repaired(_) => "Исправленное слово",
             HyphenAdverb(_) => "Наречие с дефисом",
             Initials(_) => "Инициал",
             Shaped(_) => "Не слово",
             Unknown(_) => "Неизвестное слово",
+            Custom(_) => "Пользовательский анализатор",
         }
     }
 }
@@ -93,6 +115,12 @@ impl From<Dictionary> for StackSource {
     }
 }
 
+impl From<Repaired> for StackSource {
+    fn from(source: Repaired) -> Self {
+        Repaired(source)
+    }
+}
+
 impl From<HyphenAdverb> for StackSource {
     fn from(source: HyphenAdverb) -> Self {
         HyphenAdverb(source)
@@ -117,114 +145,142 @@ impl From<Unknown> for StackSource {
     }
 }
 
+impl From<Custom> for StackSource {
+    fn from(source: Custom) -> Self {
+        Custom(source)
+    }
+}
+
 impl Source for StackSource {
     fn score(&self) -> Score {
         match *self {
             Dictionary(ref source) => source.score(),
+            Repaired(ref source) => source.score(),
             HyphenAdverb(ref source) => source.score(),
             Initials(ref source) => source.score(),
             Shaped(ref source) => source.score(),
             Unknown(ref source) => source.score(),
+            Custom(ref source) => source.score(),
         }
     }
 
     fn is_lemma(&self) -> bool {
         match *self {
             Dictionary(ref source) => source.is_lemma(),
+            Repaired(ref source) => source.is_lemma(),
             HyphenAdverb(ref source) => source.is_lemma(),
             Initials(ref source) => source.is_lemma(),
             Shaped(ref source) => source.is_lemma(),
             Unknown(ref source) => source.is_lemma(),
+            Custom(ref source) => source.is_lemma(),
         }
     }
 
     fn is_known(&self) -> bool {
         match *self {
             Dictionary(ref source) => source.is_known(),
+            Repaired(ref source) => source.is_known(),
             HyphenAdverb(ref source) => source.is_known(),
             Initials(ref source) => source.is_known(),
             Shaped(ref source) => source.is_known(),
             Unknown(ref source) => source.is_known(),
+            Custom(ref source) => source.is_known(),
         }
     }
 
     fn get_word(&self) -> Cow<str> {
         match *self {
             Dictionary(ref source) => source.get_word(),
+            Repaired(ref source) => source.get_word(),
             HyphenAdverb(ref source) => source.get_word(),
             Initials(ref source) => source.get_word(),
             Shaped(ref source) => source.get_word(),
             Unknown(ref source) => source.get_word(),
+            Custom(ref source) => source.get_word(),
         }
     }
 
     fn get_normal_form(&self, morph: &MorphAnalyzer) -> Cow<str> {
         match *self {
             Dictionary(ref source) => source.get_normal_form(morph),
+            Repaired(ref source) => source.get_normal_form(morph),
             HyphenAdverb(ref source) => source.get_normal_form(morph),
             Initials(ref source) => source.get_normal_form(morph),
             Shaped(ref source) => source.get_normal_form(morph),
             Unknown(ref source) => source.get_normal_form(morph),
+            Custom(ref source) => source.get_normal_form(morph),
         }
     }
 
     fn get_tag<'m>(&self, morph: &'m MorphAnalyzer) -> &'m OpencorporaTagReg {
         match *self {
             Dictionary(ref source) => source.get_tag(morph),
+            Repaired(ref source) => source.get_tag(morph),
             HyphenAdverb(ref source) => source.get_tag(morph),
             Initials(ref source) => source.get_tag(morph),
             Shaped(ref source) => source.get_tag(morph),
             Unknown(ref source) => source.get_tag(morph),
+            Custom(ref source) => source.get_tag(morph),
         }
     }
 
     fn try_get_para_id(&self) -> Option<ParadigmId> {
         match *self {
             Dictionary(ref source) => source.try_get_para_id(),
+            Repaired(ref source) => source.try_get_para_id(),
             HyphenAdverb(ref source) => source.try_get_para_id(),
             Initials(ref source) => source.try_get_para_id(),
             Shaped(ref source) => source.try_get_para_id(),
             Unknown(ref source) => source.try_get_para_id(),
+            Custom(ref source) => source.try_get_para_id(),
         }
     }
 
     fn write_word<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
         match *self {
             Dictionary(ref source) => source.write_word(f),
+            Repaired(ref source) => source.write_word(f),
             HyphenAdverb(ref source) => source.write_word(f),
             Initials(ref source) => source.write_word(f),
             Shaped(ref source) => source.write_word(f),
             Unknown(ref source) => source.write_word(f),
+            Custom(ref source) => source.write_word(f),
         }
     }
 
     fn write_normal_form<W: fmt::Write>(&self, f: &mut W, morph: &MorphAnalyzer) -> fmt::Result {
         match *self {
             Dictionary(ref source) => source.write_normal_form(f, morph),
+            Repaired(ref source) => source.write_normal_form(f, morph),
             HyphenAdverb(ref source) => source.write_normal_form(f, morph),
             Initials(ref source) => source.write_normal_form(f, morph),
             Shaped(ref source) => source.write_normal_form(f, morph),
             Unknown(ref source) => source.write_normal_form(f, morph),
+            Custom(ref source) => source.write_normal_form(f, morph),
         }
     }
 
     fn get_lexeme(&self, morph: &MorphAnalyzer) -> Vec<Lex> {
         match *self {
             Dictionary(ref source) => source.get_lexeme(morph),
+            Repaired(ref source) => source.get_lexeme(morph),
             HyphenAdverb(ref source) => source.get_lexeme(morph),
             Initials(ref source) => source.get_lexeme(morph),
             Shaped(ref source) => source.get_lexeme(morph),
             Unknown(ref source) => source.get_lexeme(morph),
+            Custom(ref source) => source.get_lexeme(morph),
         }
     }
 
     fn get_lemma(&self, morph: &MorphAnalyzer) -> Lex {
         match *self {
             Dictionary(ref source) => source.get_lemma(morph),
+            Repaired(ref source) => source.get_lemma(morph),
             HyphenAdverb(ref source) => source.get_lemma(morph),
             Initials(ref source) => source.get_lemma(morph),
             Shaped(ref source) => source.get_lemma(morph),
             Unknown(ref source) => source.get_lemma(morph),
+            Custom(ref source) => source.get_lemma(morph),
         }
     }
 }
@@ -233,25 +289,33 @@ impl MorphySerde for StackSource {
     fn encode<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
         match *self {
             Dictionary(ref source) => source.encode(f),
+            Repaired(ref source) => source.encode(f),
             HyphenAdverb(ref source) => source.encode(f),
             Initials(ref source) => source.encode(f),
             Shaped(ref source) => source.encode(f),
             Unknown(ref source) => source.encode(f),
+            Custom(ref source) => source.encode(f),
         }
     }
 
     fn decode(s: &str) -> Result<(&str, Self), DecodeError> {
         Ok(match try_decode::<Dictionary>(s)? {
             Some(v) => v,
-            None => match try_decode::<HyphenAdverb>(s)? {
+            None => match try_decode::<Repaired>(s)? {
                 Some(v) => v,
-                None => match try_decode::<Initials>(s)? {
+                None => match try_decode::<HyphenAdverb>(s)? {
                     Some(v) => v,
-                    None => match try_decode::<Shaped>(s)? {
+                    None => match try_decode::<Initials>(s)? {
                         Some(v) => v,
-                        None => match try_decode::<Unknown>(s)? {
+                        None => match try_decode::<Shaped>(s)? {
                             Some(v) => v,
-                            None => Err(DecodeError::UnknownPartType)?,
+                            None => match try_decode::<Unknown>(s)? {
+                                Some(v) => v,
+                                None => match try_decode::<Custom>(s)? {
+                                    Some(v) => v,
+                                    None => Err(DecodeError::UnknownPartType)?,
+                                },
+                            },
                         },
                     },
                 },
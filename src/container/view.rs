@@ -0,0 +1,56 @@
+use serde_derive::{Deserialize, Serialize};
+
+use crate::analyzer::MorphAnalyzer;
+use crate::container::abc::*;
+use crate::container::{Lex, Parsed};
+
+/// A plain view of a parse for serialization, e.g. to JSON.
+///
+/// ```
+/// use rsmorphy::prelude::*;
+///
+/// let morph = MorphAnalyzer::from_file(rsmorphy::dict_ru::DICT_PATH);
+/// let view = morph.parse("стали")[0].view(&morph);
+/// assert_eq!(view.normal_form, "стать");
+/// assert_eq!(Lex::from_id(&morph, &view.id).unwrap(), morph.parse("стали")[0].lex);
+/// let json = serde_json::to_string(&view).unwrap();
+/// assert!(json.starts_with(r#"{"word":"стали","normal_form":"стать","tag":"#));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LexView {
+    pub word: String,
+    pub normal_form: String,
+    /// The tag in the tagset of the analyzer.
+    pub tag: String,
+    /// The score of the parse; lexes out of parses, like forms of a lexeme,
+    /// have none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+    pub is_known: bool,
+    /// The encoded lex, see `Lex::from_id`.
+    pub id: String,
+}
+
+impl Lex {
+    pub fn view(&self, morph: &MorphAnalyzer) -> LexView {
+        self.view_with_score(morph, None)
+    }
+
+    fn view_with_score(&self, morph: &MorphAnalyzer, score: Option<f64>) -> LexView {
+        LexView {
+            word: self.get_word().into_owned(),
+            normal_form: self.get_normal_form(morph).into_owned(),
+            tag: morph.tag_string(self.get_tag(morph)).into_owned(),
+            score,
+            is_known: self.is_known(),
+            id: self.encoded(),
+        }
+    }
+}
+
+impl Parsed {
+    /// The view of the lex with the score of the parse.
+    pub fn view(&self, morph: &MorphAnalyzer) -> LexView {
+        self.lex.view_with_score(morph, Some(self.score.value()))
+    }
+}
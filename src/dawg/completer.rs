@@ -41,21 +41,23 @@ impl<'a> Completer<'a> {
         let mut last_index = *self.index_stack.last()?;
 
         if self.last_index != self.dict.root {
-            let entry = self.guide.units[last_index as usize];
-            let (child_label, mut sibling_label) = (entry.child, entry.sibling);
+            let child_label = self.guide.units[last_index as usize].child;
             if child_label != 0 {
                 // Follows a transition to the first child.
                 last_index = self.follow(child_label, last_index)?;
             } else {
-                while sibling_label == 0 {
-                    sibling_label = self.guide.units[last_index as usize].sibling;
+                loop {
+                    let sibling_label = self.guide.units[last_index as usize].sibling;
                     // Moves to the previous node.
                     self.key.pop();
                     self.index_stack.pop();
                     last_index = *self.index_stack.last()?;
+                    if sibling_label != 0 {
+                        // Follows a transition to the next sibling.
+                        last_index = self.follow(sibling_label, last_index)?;
+                        break;
+                    }
                 }
-                // Follows a transition to the next sibling.
-                last_index = self.follow(sibling_label, last_index)?;
             }
         }
         self.find_terminal(last_index)
@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
 use std::fs::File;
-use std::io::Read;
+use std::io::{self, Read};
 use std::marker::PhantomData;
 use std::path::Path;
 
@@ -20,6 +20,10 @@ pub struct Dawg {
 }
 
 impl Dawg {
+    pub fn new(dict: Dictionary) -> Self {
+        Dawg { dict }
+    }
+
     pub fn from_file<P>(p: P) -> Self
     where
         P: AsRef<Path>,
@@ -31,9 +35,20 @@ impl Dawg {
     where
         T: Read,
     {
-        Dawg {
-            dict: Dictionary::from_reader(fp),
-        }
+        Self::try_from_reader(fp).unwrap()
+    }
+
+    pub fn try_from_reader<T>(fp: &mut T) -> io::Result<Self>
+    where
+        T: Read,
+    {
+        Ok(Dawg {
+            dict: Dictionary::try_from_reader(fp)?,
+        })
+    }
+
+    pub fn dictionary(&self) -> &Dictionary {
+        &self.dict
     }
 
     /// Returns a list with keys of this DAWG that are prefixes of the `key`.
@@ -73,6 +88,14 @@ impl<V> CompletionDawg<V>
 where
     V: DawgValue,
 {
+    pub fn new(dawg: Dawg, guide: Guide) -> Self {
+        CompletionDawg {
+            dawg,
+            guide,
+            _phantom: PhantomData,
+        }
+    }
+
     pub fn from_file<P>(p: P) -> Self
     where
         P: AsRef<Path>,
@@ -84,11 +107,26 @@ where
     where
         T: Read,
     {
-        CompletionDawg {
-            dawg: Dawg::from_reader(fp),
-            guide: Guide::from_reader(fp),
+        Self::try_from_reader(fp).unwrap()
+    }
+
+    pub fn try_from_reader<T>(fp: &mut T) -> io::Result<Self>
+    where
+        T: Read,
+    {
+        Ok(CompletionDawg {
+            dawg: Dawg::try_from_reader(fp)?,
+            guide: Guide::try_from_reader(fp)?,
             _phantom: PhantomData,
-        }
+        })
+    }
+
+    pub fn dawg(&self) -> &Dawg {
+        &self.dawg
+    }
+
+    pub fn guide(&self) -> &Guide {
+        &self.guide
     }
 
     /// Returns a list of (key, value) tuples for all variants of `key`
@@ -104,7 +142,7 @@ where
         replaces: &BTreeMap<String, String>,
     ) -> Vec<(String, Vec<V>)> {
         let mut result: Vec<(String, Vec<V>)> = Vec::new();
-        self.similar_items_(&mut result, "", key, self.dawg.dict.root, replaces);
+        self.similar_items_(&mut result, "", key, 0, self.dawg.dict.root, replaces);
         result
     }
 
@@ -113,12 +151,14 @@ where
         result: &mut Vec<(String, Vec<V>)>,
         current_prefix: &str,
         key: &str,
+        // The position in `key` which `current_prefix` ends at; they may
+        // differ in length if the replaced chars do.
+        start_pos: usize,
         mut index: u32,
         replace_chars: &BTreeMap<String, String>,
     ) {
         log::trace!(r#"DAWG::similar_items_() index: {}"#, index);
 
-        let start_pos = current_prefix.len();
         let subkey = &key[start_pos..];
 
         let mut word_pos = start_pos;
@@ -141,7 +181,8 @@ where
                         &key[start_pos..word_pos],
                         replace_char
                     );
-                    self.similar_items_(result, &prefix, key, next_index, replace_chars);
+                    let next_pos = word_pos + b_step.len();
+                    self.similar_items_(result, &prefix, key, next_pos, next_index, replace_chars);
                 };
             }
             index = match self.dawg.dict.follow_bytes(b_step, index) {
@@ -166,16 +207,42 @@ where
         let mut completer = Completer::new(&self.dawg.dict, &self.guide, index, "");
         while let Some(key) = completer.next_key() {
             log::trace!(r#"DAWG::value_for_index_(...); key: "{:?}" "#, key);
-            let value = V::new_in_place(move |buf| {
-                let decoded = base64::decode_config_slice(key, base64::STANDARD, buf).unwrap();
-                log::trace!(r#"DAWG::value_for_index_(...); bytes: {:?} "#, buf);
-                assert_eq!(decoded, buf.len());
-            });
-            result.push(value);
+            result.push(decode_value(key));
         }
         result
     }
 
+    /// Iterates over (key, value) pairs of keys starting with the `prefix`,
+    /// in the lexicographic order of their UTF-8 bytes.
+    ///
+    /// A key with several values is yielded once per value.
+    pub fn items_with_prefix<'a>(&'a self, prefix: &str) -> ItemsWithPrefix<'a, V> {
+        let completer = self
+            .dawg
+            .dict
+            .follow_bytes(prefix, self.dawg.dict.root)
+            .map(|index| Completer::new(&self.dawg.dict, &self.guide, index, prefix));
+        ItemsWithPrefix {
+            completer,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Iterates over distinct keys starting with the `prefix`, in the
+    /// lexicographic order of their UTF-8 bytes.
+    pub fn keys_with_prefix<'a>(&'a self, prefix: &str) -> impl Iterator<Item = String> + 'a {
+        let mut last_key = None;
+        self.items_with_prefix(prefix)
+            .map(|(key, _)| key)
+            .filter(move |key| match last_key {
+                Some(ref last_key) if last_key == key => false,
+                _ => {
+                    last_key = Some(key.clone());
+                    true
+                }
+            })
+    }
+
     pub fn prefixes<'k>(&self, key: &'k str) -> Vec<&'k str> {
         self.dawg.prefixes(key)
     }
@@ -184,3 +251,42 @@ where
         self.dawg.dict.find(key)
     }
 }
+
+/// See `CompletionDawg::items_with_prefix`.
+#[derive(Debug, Clone)]
+pub struct ItemsWithPrefix<'a, V>
+where
+    V: DawgValue,
+{
+    completer: Option<Completer<'a>>,
+    _phantom: PhantomData<V>,
+}
+
+impl<'a, V> Iterator for ItemsWithPrefix<'a, V>
+where
+    V: DawgValue,
+{
+    type Item = (String, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let key = self.completer.as_mut()?.next_key()?;
+            // Keys without the separator have no value (e.g. in plain DAWGs).
+            if let Some(pos) = key.find(PAYLOAD_SEPARATOR) {
+                let value = decode_value(&key[pos + PAYLOAD_SEPARATOR.len()..]);
+                return Some((key[..pos].to_owned(), value));
+            }
+        }
+    }
+}
+
+fn decode_value<V>(b64: &str) -> V
+where
+    V: DawgValue,
+{
+    V::new_in_place(move |buf| {
+        let decoded = base64::decode_config_slice(b64, base64::STANDARD, buf).unwrap();
+        log::trace!(r#"decode_value(...); bytes: {:?} "#, buf);
+        assert_eq!(decoded, buf.len());
+    })
+}
@@ -1,11 +1,12 @@
 use std::fs::File;
-use std::io::Read;
+use std::io::{self, Read};
 use std::path::Path;
 
 use boolinator::Boolinator;
 use byteorder::{LittleEndian, ReadBytesExt};
 use flate2::read::GzDecoder;
 
+use crate::bundle::Table;
 use crate::dawg::units;
 
 /// Dictionary class for retrieval and binary I/O.
@@ -13,10 +14,18 @@ use crate::dawg::units;
 pub struct Dictionary {
     /// Root index
     pub root: u32,
-    pub units: Vec<u32>,
+    pub units: Table<u32>,
 }
 
 impl Dictionary {
+    pub fn new<U>(units: U) -> Self
+    where
+        U: Into<Table<u32>>,
+    {
+        let units = units.into();
+        Dictionary { root: 0, units }
+    }
+
     /// Reads a dictionary from a file.
     pub fn from_file(p: &Path) -> Self {
         Self::from_reader(&mut GzDecoder::new(File::open(p).unwrap()))
@@ -27,14 +36,21 @@ impl Dictionary {
     where
         T: Read,
     {
-        let size = reader.read_u32::<LittleEndian>().unwrap();
-        let units = (0..size)
-            .map(|_| reader.read_u32::<LittleEndian>().unwrap())
-            .collect();
+        Self::try_from_reader(reader).unwrap()
+    }
 
-        let root = 0;
+    /// Reads a dictionary from an input stream; fails if the stream is
+    /// broken or ends too early.
+    pub fn try_from_reader<T>(reader: &mut T) -> io::Result<Self>
+    where
+        T: Read,
+    {
+        let size = reader.read_u32::<LittleEndian>()?;
+        let units = (0..size)
+            .map(|_| reader.read_u32::<LittleEndian>())
+            .collect::<io::Result<Vec<u32>>>()?;
 
-        Dictionary { root, units }
+        Ok(Dictionary::new(units))
     }
 
     /// Checks if a given index is related to the end of a key.
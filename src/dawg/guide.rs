@@ -1,24 +1,37 @@
 use std::fs::File;
-use std::io::Read;
+use std::io::{self, Read};
 use std::path::Path;
 
 use byteorder::{LittleEndian, ReadBytesExt};
 use flate2::read::GzDecoder;
 
+use crate::bundle::{Plain, Table};
+
+#[repr(C)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct GuideEntry {
     pub child: u8,
     pub sibling: u8,
 }
 
+unsafe impl Plain for GuideEntry {}
+
 #[derive(Debug, Clone)]
 pub struct Guide {
     /// Root index
     pub root: u32,
-    pub units: Vec<GuideEntry>,
+    pub units: Table<GuideEntry>,
 }
 
 impl Guide {
+    pub fn new<U>(units: U) -> Self
+    where
+        U: Into<Table<GuideEntry>>,
+    {
+        let units = units.into();
+        Guide { root: 0, units }
+    }
+
     /// Reads a guide from a file.
     pub fn from_file(p: &Path) -> Self {
         Self::from_reader(&mut GzDecoder::new(File::open(p).unwrap()))
@@ -29,14 +42,21 @@ impl Guide {
     where
         T: Read,
     {
-        let base_size = fp.read_u32::<LittleEndian>().unwrap();
-        let units: Vec<GuideEntry> = (0..base_size)
-            .map(|_| (fp.read_u8().unwrap(), fp.read_u8().unwrap()))
-            .map(|(child, sibling)| GuideEntry { child, sibling })
-            .collect();
+        Self::try_from_reader(fp).unwrap()
+    }
 
-        let root = 0;
+    /// Reads a guide from an input stream; fails if the stream is
+    /// broken or ends too early.
+    pub fn try_from_reader<T>(fp: &mut T) -> io::Result<Self>
+    where
+        T: Read,
+    {
+        let base_size = fp.read_u32::<LittleEndian>()?;
+        let units = (0..base_size)
+            .map(|_| Ok((fp.read_u8()?, fp.read_u8()?)))
+            .map(|entry| entry.map(|(child, sibling)| GuideEntry { child, sibling }))
+            .collect::<io::Result<Vec<GuideEntry>>>()?;
 
-        Guide { root, units }
+        Ok(Guide::new(units))
     }
 }
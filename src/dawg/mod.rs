@@ -5,5 +5,5 @@ pub mod guide;
 pub mod units;
 pub mod value;
 
-pub use self::dawg::{CompletionDawg, Dawg};
+pub use self::dawg::{CompletionDawg, Dawg, ItemsWithPrefix};
 pub use self::value::{DawgValue, HH, HHH};
@@ -47,21 +47,26 @@ impl SingleTagProbabilityEstimator {
         }
     }
 
+    /// Sorts tags by P(t|w), the most probable first; keeps the order
+    /// of tags with equal probabilities.
     pub fn apply_to_tags(
         self,
         morph: &MorphAnalyzer,
         _word: &str,
         word_lower: &str,
-        tags: &mut Vec<OpencorporaTagReg>,
+        tags: &mut Vec<&OpencorporaTagReg>,
     ) {
         if tags.is_empty() {
             return;
         }
 
-        tags.sort_by(|t1: &OpencorporaTagReg, t2: &OpencorporaTagReg| {
-            self.prob(morph, word_lower, t2)
-                .partial_cmp(&self.prob(morph, word_lower, t1))
-                .unwrap_or(Ordering::Equal)
-        });
+        let mut probs: Vec<(f64, &OpencorporaTagReg)> = tags
+            .iter()
+            .map(|&tag| (self.prob(morph, word_lower, tag), tag))
+            .collect();
+        probs.sort_by(|(p1, _), (p2, _)| p2.partial_cmp(p1).unwrap_or(Ordering::Equal));
+        for (tag, (_, sorted)) in tags.iter_mut().zip(probs) {
+            *tag = sorted;
+        }
     }
 }
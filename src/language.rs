@@ -0,0 +1,85 @@
+use std::collections::BTreeMap;
+
+/// The language of a dictionary; carries the rules which the analyzer
+/// units follow apart from the dictionary itself.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    #[default]
+    Russian,
+    Ukrainian,
+}
+
+impl Language {
+    /// ```
+    /// use rsmorphy::Language;
+    ///
+    /// assert_eq!(Language::from_code("uk"), Some(Language::Ukrainian));
+    /// assert_eq!(Language::from_code("xx"), None);
+    /// ```
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "ru" => Some(Language::Russian),
+            "uk" => Some(Language::Ukrainian),
+            _ => None,
+        }
+    }
+
+    /// ISO 639-1 code, also used as the prefix of `Lex` ids.
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::Russian => "ru",
+            Language::Ukrainian => "uk",
+        }
+    }
+
+    /// Capital letters which may stand for initials.
+    pub fn letters(self) -> &'static str {
+        match self {
+            Language::Russian => "АБВГДЕЁЖЗИЙКЛМНОПРСТУФХЦЧШЩЭЮЯ",
+            Language::Ukrainian => "АБВГҐДЕЄЖЗИІЇЙКЛМНОПРСТУФХЦЧШЩЮЯ",
+        }
+    }
+
+    /// Particles which are written after a hyphen, like in "смотри-ка".
+    pub fn particles_after_hyphen(self) -> &'static [&'static str] {
+        match self {
+            Language::Russian => &["-то", "-ка", "-таки", "-де", "-тко", "-тка", "-с", "-ста"],
+            Language::Ukrainian => &["-но", "-таки", "-бо", "-от"],
+        }
+    }
+
+    /// The prefix of adverbs like "по-западному"; PyMorphy2 doesn't
+    /// detect such adverbs in Ukrainian.
+    pub fn hyphen_adverb_prefix(self) -> Option<&'static str> {
+        match self {
+            Language::Russian => Some("по-"),
+            Language::Ukrainian => None,
+        }
+    }
+
+    /// Letters which may also stand for other letters in the dictionary,
+    /// for dictionaries which don't list them in the meta.
+    pub fn char_substitutes(self) -> BTreeMap<String, String> {
+        match self {
+            Language::Russian => maplit::btreemap! {"е".into() => "ё".into()},
+            Language::Ukrainian => BTreeMap::new(),
+        }
+    }
+
+    /// The plural form agreeing with the number: `0` as in "1 яблоко",
+    /// `1` as in "2 яблока", `2` as in "5 яблок".
+    pub fn plural_form(self, num: usize) -> usize {
+        match self {
+            Language::Russian | Language::Ukrainian => match num {
+                num if (num % 10 == 1) && (num % 100 != 11) => 0,
+                num if (num % 10 >= 2)
+                    && (num % 10 <= 4)
+                    && (num % 100 < 10 || num % 100 >= 20) =>
+                {
+                    1
+                }
+                _ => 2,
+            },
+        }
+    }
+}
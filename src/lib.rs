@@ -9,9 +9,11 @@
 pub mod macros;
 
 pub mod analyzer;
+pub mod bundle;
 pub mod container;
 pub mod dawg;
 pub mod estimator;
+pub mod language;
 pub mod opencorpora;
 pub mod release;
 pub mod shapes;
@@ -24,5 +26,6 @@ pub use dict_ru;
 pub use crate::analyzer::units::abc::AnalyzerUnit;
 pub use crate::analyzer::MorphAnalyzer;
 pub use crate::container::abc::{MorphySerde, Source};
-pub use crate::container::{Lex, ParseResult, Parsed, Score};
+pub use crate::container::{InflectError, Lex, LexView, ParseResult, Parsed, Score};
+pub use crate::language::Language;
 pub use crate::opencorpora::{Grammeme, GrammemeSet};
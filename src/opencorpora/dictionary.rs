@@ -2,7 +2,7 @@ use std::borrow::Cow;
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt;
 use std::fs::File;
-use std::io::Read;
+use std::io::{self, Read};
 use std::iter::FromIterator;
 use std::path::{Path, PathBuf};
 
@@ -12,34 +12,66 @@ use maplit::hashset;
 use serde_json;
 use serde_json::Value;
 
+use crate::bundle::{Bundle, StringTable};
 use crate::container::paradigm::{ParadigmId, ParadigmIndex};
-use crate::dawg::{CompletionDawg, Dawg};
+use crate::dawg::{CompletionDawg, Dawg, DawgValue};
 pub use crate::dawg::{HH, HHH};
-use crate::opencorpora::grammeme::{Grammeme, GrammemeReg};
-use crate::opencorpora::paradigm::ParadigmEntry;
-use crate::opencorpora::tag::OpencorporaTagReg;
+use crate::language::Language;
+use crate::opencorpora::error::DictionaryError;
+use crate::opencorpora::grammeme::{
+    Grammeme, GrammemeBits, GrammemeReg, GrammemeSet, GrammemeTable,
+};
+use crate::opencorpora::paradigm::{ParadigmEntry, Paradigms};
+use crate::opencorpora::tag::{Gramtab, OpencorporaTagReg};
 use crate::util::DumbProfiler;
 
 pub type WordsDawg = CompletionDawg<HH>;
 pub type PredictionSuffixesDawg = CompletionDawg<HHH>;
 pub type ConditionalProbDistDawg = CompletionDawg<HH>;
 
+/// Aliases of grammemes which only the analyzer units produce,
+/// as PyMorphy2 spells them.
+const EXTRA_GRAMMEME_ALIASES: &[(&str, &str)] = &[
+    ("LATN", "ЛАТ"),
+    ("PNCT", "ЗПР"),
+    ("NUMB", "ЧИСЛО"),
+    ("intg", "цел"),
+    ("real", "вещ"),
+    ("ROMN", "РИМ"),
+    ("UNKN", "НЕИЗВ"),
+];
+
+/// Grammemes to take for rare ones which are missing in a lexeme, like
+/// pymorphy2 does; they hold for dictionaries without the grammeme hierarchy.
+const GRAMMEME_FALLBACKS: &[(&str, &str)] = &[
+    ("gen2", "gent"),
+    ("acc2", "accs"),
+    ("loc2", "loct"),
+    ("voct", "nomn"),
+];
+
 #[derive(Debug, Default, Clone)]
 pub struct GrammemeMeta {
+    /// The index of the grammeme in `grammemes.json`; grammemes of the analyzer
+    /// units follow the ones of the dictionary.
     pub index: usize,
     pub children: HashSet<Grammeme>,
     pub incompatible: HashSet<Grammeme>,
+    pub incompatible_bits: GrammemeBits,
 }
 
 /// Open Corpora dictionary wrapper class.
 #[derive(Debug, Clone)]
 pub struct Dictionary {
     pub meta: HashMap<String, Value>,
+    pub language: Language,
     pub grammemes: HashMap<Grammeme, GrammemeReg>,
     pub grammeme_metas: HashMap<Grammeme, GrammemeMeta>,
-    pub gramtab: Vec<OpencorporaTagReg>,
+    /// Grammemes by their `GrammemeMeta::index`.
+    pub grammeme_indices: Vec<Grammeme>,
+    pub gramtab: Gramtab,
     pub suffixes: Vec<String>,
-    pub paradigms: Vec<Vec<ParadigmEntry>>,
+    pub paradigms: Paradigms,
     pub words: WordsDawg,
     pub p_t_given_w: ConditionalProbDistDawg,
     pub prediction_prefixes: Dawg,
@@ -47,15 +79,38 @@ pub struct Dictionary {
     pub paradigm_prefixes: Vec<String>,
     pub paradigm_prefixes_rev: Vec<(u16, String)>,
     pub prediction_splits: Vec<usize>,
+    /// Letters of a word which may also stand for other letters
+    /// in the dictionary, like "е" for "ё".
     pub char_substitutes: BTreeMap<String, String>,
 }
 
-struct PathLoader {
+/// Reads parts of a dictionary, either from a directory or from a bundle.
+pub(crate) trait Loader {
+    /// The file to name in errors about the part `name`.
+    fn path(&self, name: &str) -> PathBuf;
+
+    fn json<T>(&self, name: &str) -> Result<T, DictionaryError>
+    where
+        for<'de> T: ::serde::Deserialize<'de>;
+
+    /// Reads a JSON list of strings.
+    fn strings(&self, name: &str) -> Result<StringTable, DictionaryError>;
+
+    fn paradigms(&self, name: &str) -> Result<Paradigms, DictionaryError>;
+
+    fn dawg(&self, name: &str) -> Result<Dawg, DictionaryError>;
+
+    fn completion_dawg<V>(&self, name: &str) -> Result<CompletionDawg<V>, DictionaryError>
+    where
+        V: DawgValue;
+}
+
+pub(crate) struct PathLoader {
     dict_path: PathBuf,
 }
 
 impl PathLoader {
-    fn new<P>(p: P) -> Self
+    pub(crate) fn new<P>(p: P) -> Self
     where
         P: AsRef<Path>,
     {
@@ -63,65 +118,204 @@ impl PathLoader {
         PathLoader { dict_path }
     }
 
-    fn path<S>(&self, name: S) -> PathBuf
+    fn reader(&self, name: &str) -> Result<GzDecoder<File>, DictionaryError> {
+        let path = self.path(name);
+        match File::open(&path) {
+            Ok(file) => Ok(GzDecoder::new(file)),
+            Err(source) => Err(DictionaryError::Io { file: path, source }),
+        }
+    }
+
+    /// Reads the whole file unpacked.
+    pub(crate) fn raw(&self, name: &str) -> Result<Vec<u8>, DictionaryError> {
+        let mut data = Vec::new();
+        self.reader(name)?
+            .read_to_end(&mut data)
+            .map_err(|e| DictionaryError::read(self.path(name), e))?;
+        Ok(data)
+    }
+
+    fn read_dawg<T, F>(&self, name: &str, from_reader: F) -> Result<T, DictionaryError>
     where
-        S: AsRef<Path>,
+        F: FnOnce(&mut GzDecoder<File>) -> io::Result<T>,
     {
+        from_reader(&mut self.reader(name)?)
+            .map_err(|e| DictionaryError::read_dawg(self.path(name), e))
+    }
+}
+
+impl Loader for PathLoader {
+    fn path(&self, name: &str) -> PathBuf {
         self.dict_path.join(name)
     }
 
-    fn reader<S>(&self, name: S) -> impl Read
+    fn json<T>(&self, name: &str) -> Result<T, DictionaryError>
     where
-        S: AsRef<Path>,
+        for<'de> T: ::serde::Deserialize<'de>,
     {
-        GzDecoder::new(File::open(&self.path(name)).unwrap())
+        serde_json::from_slice(&self.raw(name)?).map_err(|source| DictionaryError::Json {
+            file: self.path(name),
+            source,
+        })
+    }
+
+    fn strings(&self, name: &str) -> Result<StringTable, DictionaryError> {
+        let strings: Vec<String> = self.json(name)?;
+        Ok(StringTable::new(&strings))
+    }
+
+    fn paradigms(&self, name: &str) -> Result<Paradigms, DictionaryError> {
+        let file = self.path(name);
+        let reader = &mut self.reader(name)?;
+        let read_paradigm = |reader: &mut GzDecoder<File>| -> io::Result<Vec<u16>> {
+            let paradigm_len = reader.read_u16::<LittleEndian>()?;
+            (0..paradigm_len)
+                .map(|_| reader.read_u16::<LittleEndian>())
+                .collect()
+        };
+
+        let paradigms_count = reader
+            .read_u16::<LittleEndian>()
+            .map_err(|e| DictionaryError::read(&file, e))?;
+        (0..paradigms_count as usize)
+            .map(|paradigm| {
+                let entries = read_paradigm(reader).map_err(|e| DictionaryError::read(&file, e))?;
+                match entries.len() % 3 {
+                    0 => Ok(ParadigmEntry::build(entries)),
+                    _ => Err(DictionaryError::ParadigmLength {
+                        file: file.clone(),
+                        paradigm,
+                        len: entries.len(),
+                    }),
+                }
+            })
+            .collect()
     }
 
-    fn json<S, T>(&self, name: S) -> serde_json::Result<T>
+    fn dawg(&self, name: &str) -> Result<Dawg, DictionaryError> {
+        self.read_dawg(name, Dawg::try_from_reader)
+    }
+
+    fn completion_dawg<V>(&self, name: &str) -> Result<CompletionDawg<V>, DictionaryError>
     where
-        S: AsRef<Path>,
-        for<'de> T: ::serde::Deserialize<'de>,
+        V: DawgValue,
     {
-        serde_json::from_reader(self.reader(name))
+        self.read_dawg(name, CompletionDawg::try_from_reader)
     }
 }
 
 impl Dictionary {
+    /// Loads the dictionary; panics if it can't be loaded.
     pub fn from_file<P>(p: P) -> Self
     where
         P: AsRef<Path>,
     {
-        let load = PathLoader::new(p);
+        Self::try_from_file(p).unwrap_or_else(|e| panic!("Failed to load dictionary: {}", e))
+    }
+
+    /// Loads the dictionary; the error names the file which failed to load.
+    pub fn try_from_file<P>(p: P) -> Result<Self, DictionaryError>
+    where
+        P: AsRef<Path>,
+    {
+        Self::load(&PathLoader::new(p))
+    }
+
+    /// Maps the dictionary compiled into a single file with `bundle::compile`;
+    /// panics if it can't be loaded.
+    pub fn from_bundle<P>(p: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        Self::try_from_bundle(p).unwrap_or_else(|e| panic!("Failed to load dictionary: {}", e))
+    }
 
+    /// Maps the dictionary compiled into a single file with `bundle::compile`.
+    ///
+    /// The DAWGs and the paradigms borrow from the mapping, which is shared
+    /// with other processes using the same bundle.
+    pub fn try_from_bundle<P>(p: P) -> Result<Self, DictionaryError>
+    where
+        P: AsRef<Path>,
+    {
+        Self::load(&Bundle::open(p)?)
+    }
+
+    fn load<L>(load: &L) -> Result<Self, DictionaryError>
+    where
+        L: Loader,
+    {
         let mut profiler = DumbProfiler::start();
 
-        let meta: Vec<(String, Value)> = load.json("meta.json.gz").expect("object of `Value`s");
+        let meta_file = load.path("meta.json.gz");
+        let meta: Vec<(String, Value)> = load.json("meta.json.gz")?;
         let meta = HashMap::from_iter(meta.into_iter());
         profiler.waypoint("meta");
 
+        let compile_options = meta
+            .get("compile_options")
+            .and_then(Value::as_object)
+            .ok_or_else(|| {
+                DictionaryError::json_shape(&meta_file, "no `compile_options` object")
+            })?;
         let paradigm_prefixes: Vec<String> = {
-            meta["compile_options"]
-                .as_object()
-                .unwrap()
+            compile_options
                 .get("paradigm_prefixes")
-                .unwrap()
-                .as_array()
-                .unwrap()
-                .iter()
-                .map(|v| v.as_str().unwrap().to_owned())
-                .collect()
+                .and_then(Value::as_array)
+                .and_then(|prefixes| {
+                    prefixes
+                        .iter()
+                        .map(|v| v.as_str().map(str::to_owned))
+                        .collect()
+                })
+                .ok_or_else(|| {
+                    DictionaryError::json_shape(&meta_file, "no `paradigm_prefixes` strings")
+                })?
         };
         let max_suffix_length = {
             meta.get("prediction_options")
-                .unwrap_or_else(|| &meta["compile_options"])
-                .as_object()
-                .unwrap()
+                .and_then(Value::as_object)
+                .unwrap_or(compile_options)
                 .get("max_suffix_length")
-                .unwrap()
-                .as_u64()
-                .unwrap() as usize
+                .and_then(Value::as_u64)
+                .ok_or_else(|| {
+                    DictionaryError::json_shape(&meta_file, "no `max_suffix_length` number")
+                })? as usize
         };
         let prediction_splits = (1..=max_suffix_length).rev().collect();
+        let language = match meta.get("language_code") {
+            // Old dictionaries were Russian only.
+            None => Language::Russian,
+            Some(code) => code.as_str().and_then(Language::from_code).ok_or_else(|| {
+                DictionaryError::json_shape(
+                    &meta_file,
+                    format!("unsupported `language_code` {}", code),
+                )
+            })?,
+        };
+        let char_substitutes = match meta.get("char_substitutes") {
+            Some(substitutes) => substitutes
+                .as_object()
+                .and_then(|substitutes| {
+                    substitutes
+                        .iter()
+                        .map(|(from, to)| match to.as_str() {
+                            Some(to) if is_single_char(from) && is_single_char(to) => {
+                                Some((from.to_owned(), to.to_owned()))
+                            }
+                            _ => None,
+                        })
+                        .collect()
+                })
+                .ok_or_else(|| {
+                    DictionaryError::json_shape(
+                        &meta_file,
+                        "`char_substitutes` is not a map of single characters",
+                    )
+                })?,
+            // Dictionaries compiled by pymorphy2 don't keep the substitutes.
+            None => language.char_substitutes(),
+        };
         profiler.waypoint("meta'");
 
         let paradigm_prefixes_rev = paradigm_prefixes
@@ -132,40 +326,66 @@ impl Dictionary {
             .collect();
         profiler.waypoint("paradigm_prefixes_rev");
 
-        let suffixes = load.json("suffixes.json.gz").expect("array of strings");
+        let suffixes = load.json("suffixes.json.gz")?;
         profiler.waypoint("suffixes");
 
-        let gramtab: Vec<String> = load
-            .json("gramtab-opencorpora-int.json.gz")
-            .expect("array of strings");
-        profiler.waypoint("gramtab");
-        // TODO opencorpora-ext
-        let gramtab = gramtab.into_iter().map(OpencorporaTagReg::new).collect();
-        profiler.waypoint("gramtab'");
-
         // TODO join `grammemes` and `grammeme_metas` into one set
-        let grammemes: Vec<Vec<Value>> = load.json("grammemes.json.gz").expect("array of `Value`s");
+        let grammemes_file = load.path("grammemes.json.gz");
+        let grammemes: Vec<Vec<Value>> = load.json("grammemes.json.gz")?;
         profiler.waypoint("grammemes");
         let grammemes = grammemes
             .into_iter()
-            .map(GrammemeReg::from_json)
-            .map(|gr| (gr.name.clone(), gr));
+            .map(|gr| {
+                GrammemeReg::try_from_json(gr)
+                    .map(|gr| (gr.name.clone(), gr))
+                    .map_err(|reason| DictionaryError::json_shape(&grammemes_file, reason))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut grammeme_indices: Vec<Grammeme> = Vec::with_capacity(grammemes.len());
+        for (grammeme, _) in &grammemes {
+            if !grammeme_indices.contains(grammeme) {
+                grammeme_indices.push(grammeme.clone());
+            }
+        }
+        grammeme_indices.extend(
+            EXTRA_GRAMMEME_ALIASES
+                .iter()
+                .map(|&(name, _)| Grammeme::new(name))
+                .filter(|grammeme| !grammeme_indices.contains(grammeme))
+                .collect::<Vec<_>>(),
+        );
+        if grammeme_indices.len() > GrammemeBits::CAPACITY {
+            return Err(DictionaryError::json_shape(
+                &grammemes_file,
+                format!(
+                    "{} grammemes, but at most {} are supported",
+                    grammeme_indices.len(),
+                    GrammemeBits::CAPACITY
+                ),
+            ));
+        }
         let grammemes = HashMap::from_iter(grammemes);
         let grammeme_metas = {
             let mut grammeme_metas = HashMap::<Grammeme, GrammemeMeta>::default();
-            for (index, grammeme) in grammemes.keys().enumerate() {
-                if !grammeme_metas.contains_key(grammeme) {
-                    grammeme_metas.insert(grammeme.clone(), GrammemeMeta {
+            for (index, grammeme) in grammeme_indices.iter().enumerate() {
+                grammeme_metas.insert(
+                    grammeme.clone(),
+                    GrammemeMeta {
                         index,
                         ..GrammemeMeta::default()
-                    });
-                }
+                    },
+                );
             }
             for (grammeme, gram_reg) in &grammemes {
                 if let Some(ref parent) = gram_reg.parent {
                     grammeme_metas
                         .get_mut(parent)
-                        .unwrap()
+                        .ok_or_else(|| {
+                            DictionaryError::json_shape(
+                                &grammemes_file,
+                                format!("unknown parent {:?} of {:?}", parent, grammeme),
+                            )
+                        })?
                         .children
                         .insert(grammeme.clone());
                 }
@@ -176,7 +396,9 @@ impl Dictionary {
             let plur = Grammeme::new("plur");
             let gndr = Grammeme::new("GNdr");
             let mut extra_incompatible = hashset! { gndr.clone() };
-            extra_incompatible.extend(grammeme_metas[&gndr].children.iter().cloned());
+            if let Some(gndr_meta) = grammeme_metas.get(&gndr) {
+                extra_incompatible.extend(gndr_meta.children.iter().cloned());
+            }
 
             for grammeme in grammemes.keys() {
                 let gm: &mut GrammemeMeta = grammeme_metas.get_mut(grammeme).unwrap();
@@ -187,33 +409,65 @@ impl Dictionary {
                 gm.incompatible
                     .extend(gm.children.iter().cloned().filter(|v| v != grammeme));
             }
+            let index_of = |grammeme: &Grammeme| grammeme_metas[grammeme].index;
+            let incompatible_bits: Vec<_> = grammeme_metas
+                .iter()
+                .map(|(grammeme, gm)| {
+                    let bits = gm.incompatible.iter().map(index_of).collect();
+                    (grammeme.clone(), bits)
+                })
+                .collect();
+            for (grammeme, bits) in incompatible_bits {
+                grammeme_metas.get_mut(&grammeme).unwrap().incompatible_bits = bits;
+            }
             grammeme_metas
         };
         profiler.waypoint("grammemes'");
 
-        let paradigms = load_paradigms(&mut load.reader("paradigms.array.gz"));
+        let gramtab = load.strings("gramtab-opencorpora-int.json.gz")?;
+        let gramtab_ext = load.strings("gramtab-opencorpora-ext.json.gz")?;
+        profiler.waypoint("gramtab");
+        if gramtab.len() != gramtab_ext.len() {
+            return Err(DictionaryError::json_shape(
+                load.path("gramtab-opencorpora-ext.json.gz"),
+                format!(
+                    "{} tags, but {} in the opencorpora-int gramtab",
+                    gramtab_ext.len(),
+                    gramtab.len()
+                ),
+            ));
+        }
+        let indices = grammeme_metas
+            .iter()
+            .map(|(grammeme, meta)| (grammeme.clone(), meta.index))
+            .collect();
+        let gramtab = Gramtab::new(
+            gramtab,
+            gramtab_ext,
+            GrammemeTable::new(&grammemes),
+            indices,
+        );
+        profiler.waypoint("gramtab'");
+
+        let paradigms = load.paradigms("paradigms.array.gz")?;
         profiler.waypoint("paradigms");
-        let words = CompletionDawg::from_reader(&mut load.reader("words.dawg.gz"));
+        let words = load.completion_dawg("words.dawg.gz")?;
         profiler.waypoint("words");
-        let p_t_given_w = CompletionDawg::from_reader(&mut load.reader("p_t_given_w.intdawg.gz"));
+        let p_t_given_w = load.completion_dawg("p_t_given_w.intdawg.gz")?;
         profiler.waypoint("p_t_given_w");
-        let prediction_prefixes =
-            Dawg::from_reader(&mut load.reader("prediction-prefixes.dawg.gz"));
+        let prediction_prefixes = load.dawg("prediction-prefixes.dawg.gz")?;
         profiler.waypoint("prediction_prefixes");
-        let prediction_suffixes_dawgs = Vec::from_iter((0..paradigm_prefixes.len()).map(|i| {
-            CompletionDawg::from_reader(
-                &mut load.reader(format!("prediction-suffixes-{}.dawg.gz", i)),
-            )
-        }));
+        let prediction_suffixes_dawgs = (0..paradigm_prefixes.len())
+            .map(|i| load.completion_dawg(&format!("prediction-suffixes-{}.dawg.gz", i)))
+            .collect::<Result<_, _>>()?;
         profiler.waypoint("prediction_suffixes_dawgs");
 
-        // TODO load char_substitutes
-        let char_substitutes = maplit::btreemap! {"е".into() => "ё".into()};
-
-        Dictionary {
+        Ok(Dictionary {
             meta,
+            language,
             grammemes,
             grammeme_metas,
+            grammeme_indices,
             gramtab,
             suffixes,
             paradigms,
@@ -225,7 +479,109 @@ impl Dictionary {
             paradigm_prefixes_rev,
             prediction_splits,
             char_substitutes,
+        })
+    }
+
+    /// Spells the opencorpora-int tag with opencorpora-ext grammemes;
+    /// unknown grammemes are kept as is.
+    ///
+    /// ```
+    /// use rsmorphy::opencorpora::Dictionary;
+    ///
+    /// let dict = Dictionary::from_file(rsmorphy::dict_ru::DICT_PATH);
+    /// assert_eq!(dict.translate_to_ext("NOUN,anim,masc sing,nomn"), "СУЩ,од,мр ед,им");
+    /// assert_eq!(dict.translate_to_ext("NUMB,intg"), "ЧИСЛО,цел");
+    /// ```
+    pub fn translate_to_ext(&self, tag: &str) -> String {
+        let mut ext = String::with_capacity(tag.len() * 2);
+        let mut start = 0;
+        for (end, sep) in tag.match_indices(&[',', ' '][..]) {
+            ext.push_str(self.grammeme_ext(&tag[start..end]));
+            ext.push_str(sep);
+            start = end + sep.len();
         }
+        ext.push_str(self.grammeme_ext(&tag[start..]));
+        ext
+    }
+
+    fn grammeme_ext<'a>(&'a self, grammeme: &'a str) -> &'a str {
+        match self.grammemes.get(&Grammeme::new(grammeme)) {
+            Some(reg) => &reg.alias,
+            None => EXTRA_GRAMMEME_ALIASES
+                .iter()
+                .find(|&&(name, _)| name == grammeme)
+                .map_or(grammeme, |&(_, alias)| alias),
+        }
+    }
+
+    /// The grammeme to take for the one which is missing in a lexeme,
+    /// like "loct" for "loc2": the parent of the grammeme, but never
+    /// a category like "CAse".
+    ///
+    /// ```
+    /// use rsmorphy::opencorpora::Dictionary;
+    /// use rsmorphy::Grammeme;
+    ///
+    /// let dict = Dictionary::from_file(rsmorphy::dict_ru::DICT_PATH);
+    /// let fallback = |g| dict.grammeme_fallback(&Grammeme::new(g));
+    /// assert_eq!(fallback("loc2"), Some(Grammeme::new("loct")));
+    /// assert_eq!(fallback("gen1"), Some(Grammeme::new("gent")));
+    /// assert_eq!(fallback("loct"), None);
+    /// ```
+    pub fn grammeme_fallback(&self, grammeme: &Grammeme) -> Option<Grammeme> {
+        if let Some(&(_, fallback)) = GRAMMEME_FALLBACKS
+            .iter()
+            .find(|&&(rare, _)| rare == grammeme.as_str())
+        {
+            return Some(Grammeme::new(fallback));
+        }
+        let parent = self.grammemes.get(grammeme)?.parent.as_ref()?;
+        // Categories have no parents.
+        self.grammemes.get(parent)?.parent.as_ref()?;
+        Some(parent.clone())
+    }
+
+    /// Grammemes of the set as bits, if all of them are known to the dictionary.
+    ///
+    /// ```
+    /// use rsmorphy::opencorpora::{Dictionary, GrammemeSet};
+    ///
+    /// let dict = Dictionary::from_file(rsmorphy::dict_ru::DICT_PATH);
+    /// let bits = dict.grammeme_bits(&GrammemeSet::new("NOUN,anim,masc sing,nomn")).unwrap();
+    /// assert_eq!(bits.len(), 5);
+    /// assert_eq!(dict.grammemes_string(bits), "NOUN,anim,masc,sing,nomn");
+    /// assert_eq!(dict.grammeme_bits(&GrammemeSet::new("NOUN,Abcd")), None);
+    /// ```
+    pub fn grammeme_bits(&self, grammemes: &GrammemeSet) -> Option<GrammemeBits> {
+        grammemes
+            .set
+            .iter()
+            .map(|grammeme| self.grammeme_metas.get(grammeme).map(|gm| gm.index))
+            .collect()
+    }
+
+    /// Bits of the tag, computed for tags made outside of the dictionary.
+    pub fn tag_bits(&self, tag: &OpencorporaTagReg) -> Option<GrammemeBits> {
+        tag.bits.or_else(|| self.grammeme_bits(&tag.grammemes))
+    }
+
+    pub fn grammeme_set(&self, bits: GrammemeBits) -> GrammemeSet {
+        GrammemeSet {
+            set: self.iter_grammemes(bits).cloned().collect(),
+        }
+    }
+
+    /// Grammemes in the order of the dictionary, separated by commas.
+    pub fn grammemes_string(&self, bits: GrammemeBits) -> String {
+        self.iter_grammemes(bits)
+            .map(Grammeme::as_str)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    fn iter_grammemes(&self, bits: GrammemeBits) -> impl Iterator<Item = &Grammeme> {
+        bits.iter()
+            .filter_map(move |index| self.grammeme_indices.get(index))
     }
 
     pub fn get_paradigm<Id>(&self, id: Id) -> &[ParadigmEntry]
@@ -250,6 +606,22 @@ impl Dictionary {
         &self.gramtab[self.get_paradigm_entry(id, idx).tag_id as usize]
     }
 
+    /// The shared tag of the gramtab, unlike a new one parsed with
+    /// `OpencorporaTagReg::new`; `None` if the dictionary has no such tag.
+    ///
+    /// ```
+    /// use rsmorphy::opencorpora::Dictionary;
+    ///
+    /// let dict = Dictionary::from_file(rsmorphy::dict_ru::DICT_PATH);
+    /// let tag = dict.tag("NOUN,inan,masc sing,nomn").unwrap();
+    /// assert!(std::ptr::eq(tag, dict.tag("NOUN,inan,masc sing,nomn").unwrap()));
+    /// assert_eq!(tag.get_ext_string(&dict), "СУЩ,неод,мр ед,им");
+    /// assert!(dict.tag("NOUN,inan,masc sing,xxxx").is_none());
+    /// ```
+    pub fn tag(&self, string: &str) -> Option<&OpencorporaTagReg> {
+        self.gramtab.find(string)
+    }
+
     /// Return a list of
     ///     (prefix, tag, suffix)
     /// tuples representing the paradigm.
@@ -319,15 +691,6 @@ impl Dictionary {
     }
 }
 
-fn load_paradigms<R: Read>(reader: &mut R) -> Vec<Vec<ParadigmEntry>> {
-    let paradigms_count = reader.read_u16::<LittleEndian>().unwrap();
-    (0..paradigms_count)
-        .map(|_| {
-            let paradigm_len = reader.read_u16::<LittleEndian>().unwrap();
-            (0..paradigm_len)
-                .map(|_| reader.read_u16::<LittleEndian>().unwrap())
-                .collect::<Vec<u16>>()
-        })
-        .map(ParadigmEntry::build)
-        .collect()
+fn is_single_char(s: &str) -> bool {
+    s.chars().count() == 1
 }
@@ -0,0 +1,124 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+/// An error that occurs while loading a dictionary; names the failed file.
+#[derive(Debug)]
+pub enum DictionaryError {
+    /// The file can't be opened or read
+    Io { file: PathBuf, source: io::Error },
+    /// The file is not a valid gzip stream
+    Gzip { file: PathBuf, source: io::Error },
+    /// The file is not a valid JSON
+    Json {
+        file: PathBuf,
+        source: serde_json::Error,
+    },
+    /// The JSON is valid, but its structure is not expected
+    JsonShape { file: PathBuf, reason: String },
+    /// The DAWG ends before all its units are read
+    TruncatedDawg { file: PathBuf },
+    /// The paradigm length is not a multiple of 3
+    ParadigmLength {
+        file: PathBuf,
+        paradigm: usize,
+        len: usize,
+    },
+    /// The file is not a valid bundle or lacks a section
+    Bundle { file: PathBuf, reason: String },
+}
+
+impl DictionaryError {
+    pub fn file(&self) -> &PathBuf {
+        use self::DictionaryError::*;
+
+        match self {
+            Io { file, .. }
+            | Gzip { file, .. }
+            | Json { file, .. }
+            | JsonShape { file, .. }
+            | TruncatedDawg { file }
+            | ParadigmLength { file, .. }
+            | Bundle { file, .. } => file,
+        }
+    }
+
+    /// Classifies an error which occurs while reading a gzipped file.
+    pub(crate) fn read<P>(file: P, source: io::Error) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        let file = file.into();
+        match source.kind() {
+            io::ErrorKind::InvalidInput | io::ErrorKind::InvalidData => {
+                DictionaryError::Gzip { file, source }
+            }
+            _ => DictionaryError::Io { file, source },
+        }
+    }
+
+    /// Classifies an error which occurs while reading a DAWG.
+    pub(crate) fn read_dawg<P>(file: P, source: io::Error) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        match source.kind() {
+            io::ErrorKind::UnexpectedEof => DictionaryError::TruncatedDawg { file: file.into() },
+            _ => DictionaryError::read(file, source),
+        }
+    }
+
+    pub(crate) fn json_shape<P, R>(file: P, reason: R) -> Self
+    where
+        P: Into<PathBuf>,
+        R: Into<String>,
+    {
+        let file = file.into();
+        let reason = reason.into();
+        DictionaryError::JsonShape { file, reason }
+    }
+
+    pub(crate) fn bundle<P, R>(file: P, reason: R) -> Self
+    where
+        P: Into<PathBuf>,
+        R: Into<String>,
+    {
+        let file = file.into();
+        let reason = reason.into();
+        DictionaryError::Bundle { file, reason }
+    }
+}
+
+impl fmt::Display for DictionaryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::DictionaryError::*;
+
+        let file = self.file().display();
+        match self {
+            Io { source, .. } => write!(f, "{}: I/O error: {}", file, source),
+            Gzip { source, .. } => write!(f, "{}: broken gzip stream: {}", file, source),
+            Json { source, .. } => write!(f, "{}: invalid JSON: {}", file, source),
+            JsonShape { reason, .. } => write!(f, "{}: unexpected JSON: {}", file, reason),
+            TruncatedDawg { .. } => write!(f, "{}: truncated DAWG", file),
+            ParadigmLength { paradigm, len, .. } => write!(
+                f,
+                "{}: paradigm #{} has length {}, which is not a multiple of 3",
+                file, paradigm, len
+            ),
+            Bundle { reason, .. } => write!(f, "{}: broken bundle: {}", file, reason),
+        }
+    }
+}
+
+impl Error for DictionaryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        use self::DictionaryError::*;
+
+        match self {
+            Io { source, .. } | Gzip { source, .. } => Some(source),
+            Json { source, .. } => Some(source),
+            JsonShape { .. } | TruncatedDawg { .. } | ParadigmLength { .. } | Bundle { .. } => None,
+        }
+    }
+}
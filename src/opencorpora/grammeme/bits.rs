@@ -0,0 +1,121 @@
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::{BitAnd, BitOr, Sub};
+
+/// A set of grammemes as bits at their indices in a dictionary,
+/// see `Dictionary::grammeme_bits`.
+///
+/// Indices follow the order of grammemes in the dictionary files, so the bits
+/// of a set may be stored as an integer while the dictionary is the same.
+///
+/// ```
+/// use rsmorphy::opencorpora::grammeme::GrammemeBits;
+///
+/// let a: GrammemeBits = vec![1, 5, 100].into_iter().collect();
+/// let b: GrammemeBits = vec![5, 7].into_iter().collect();
+/// assert_eq!((a & b).iter().collect::<Vec<_>>(), vec![5]);
+/// assert_eq!((a | b).len(), 4);
+/// assert_eq!((a - b).iter().collect::<Vec<_>>(), vec![1, 100]);
+/// assert_eq!(GrammemeBits::from_bits(a.bits()), a);
+/// ```
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GrammemeBits {
+    bits: u128,
+}
+
+impl GrammemeBits {
+    /// The maximal number of grammemes in a dictionary.
+    pub const CAPACITY: usize = 128;
+
+    pub fn new() -> Self {
+        GrammemeBits::default()
+    }
+
+    pub fn from_bits(bits: u128) -> Self {
+        GrammemeBits { bits }
+    }
+
+    pub fn bits(self) -> u128 {
+        self.bits
+    }
+
+    /// # Panics
+    ///
+    /// If `index` is not less than `CAPACITY`.
+    ///
+    /// ```should_panic
+    /// use rsmorphy::opencorpora::grammeme::GrammemeBits;
+    ///
+    /// GrammemeBits::new().insert(GrammemeBits::CAPACITY);
+    /// ```
+    pub fn insert(&mut self, index: usize) {
+        assert!(
+            index < Self::CAPACITY,
+            "grammeme index {} is out of capacity {}",
+            index,
+            Self::CAPACITY
+        );
+        self.bits |= 1 << index;
+    }
+
+    pub fn contains(self, index: usize) -> bool {
+        index < Self::CAPACITY && self.bits & (1 << index) != 0
+    }
+
+    pub fn is_subset(self, other: Self) -> bool {
+        self.bits & !other.bits == 0
+    }
+
+    pub fn len(self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+
+    /// Indices of the grammemes in ascending order.
+    pub fn iter(self) -> impl Iterator<Item = usize> {
+        (0..Self::CAPACITY).filter(move |&index| self.contains(index))
+    }
+}
+
+impl BitOr for GrammemeBits {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        GrammemeBits::from_bits(self.bits | other.bits)
+    }
+}
+
+impl BitAnd for GrammemeBits {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        GrammemeBits::from_bits(self.bits & other.bits)
+    }
+}
+
+impl Sub for GrammemeBits {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        GrammemeBits::from_bits(self.bits & !other.bits)
+    }
+}
+
+impl FromIterator<usize> for GrammemeBits {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut bits = GrammemeBits::new();
+        for index in iter {
+            bits.insert(index);
+        }
+        bits
+    }
+}
+
+impl fmt::Debug for GrammemeBits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
@@ -1,3 +1,7 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
 use string_cache::DefaultAtom;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -9,4 +13,55 @@ impl Grammeme {
     pub fn new<A: Into<DefaultAtom>>(a: A) -> Self {
         Grammeme { atom: a.into() }
     }
+
+    pub fn as_str(&self) -> &str {
+        &self.atom
+    }
+}
+
+impl<'a> From<&'a str> for Grammeme {
+    fn from(s: &'a str) -> Self {
+        Grammeme::new(s)
+    }
+}
+
+impl fmt::Display for Grammeme {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// ```
+/// use rsmorphy::Grammeme;
+///
+/// assert_eq!("V-ey".parse(), Ok(Grammeme::new("V-ey")));
+/// assert!("NOUN,anim".parse::<Grammeme>().is_err());
+/// ```
+impl FromStr for Grammeme {
+    type Err = InvalidGrammeme;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.is_empty() && s.chars().all(is_grammeme_char) {
+            Ok(Grammeme::new(s))
+        } else {
+            Err(InvalidGrammeme(s.to_owned()))
+        }
+    }
+}
+
+/// Grammemes are like "NOUN", "1per" or "V-ey".
+pub(crate) fn is_grammeme_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '-'
+}
+
+/// A string which is not a grammeme, like an empty one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidGrammeme(pub String);
+
+impl fmt::Display for InvalidGrammeme {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid grammeme {:?}", self.0)
+    }
 }
+
+impl Error for InvalidGrammeme {}
@@ -1,7 +1,11 @@
+pub mod bits;
 pub mod grammeme;
 pub mod reg;
 pub mod set;
+pub mod table;
 
-pub use self::grammeme::Grammeme;
+pub use self::bits::GrammemeBits;
+pub use self::grammeme::{Grammeme, InvalidGrammeme};
 pub use self::reg::GrammemeReg;
 pub use self::set::GrammemeSet;
+pub use self::table::GrammemeTable;
@@ -13,21 +13,35 @@ pub struct GrammemeReg {
 
 impl GrammemeReg {
     pub fn from_json(array: Vec<Value>) -> Self {
-        assert_eq!(array.len(), 4, "Expected 4 elements in array");
+        Self::try_from_json(array).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Reads an entry of `grammemes.json`: `[name, parent, alias, description]`.
+    pub fn try_from_json(array: Vec<Value>) -> Result<Self, String> {
+        if array.len() != 4 {
+            return Err(format!("Expected 4 elements in array, found: {:?}", array));
+        }
         let mut array = array.into_iter().map(|v| match v {
-            Value::Null => None,
-            Value::String(string) => (!string.is_empty()).as_some(string),
-            wrong_value => panic!(
+            Value::Null => Ok(None),
+            Value::String(string) => Ok((!string.is_empty()).as_some(string)),
+            wrong_value => Err(format!(
                 "Expected null or non-empty string, found: {:?}",
                 wrong_value
-            ),
+            )),
         });
         let mut next = || array.next().unwrap();
-        GrammemeReg {
-            name: next().map(Grammeme::new).expect("string"),
-            parent: next().map(Grammeme::new),
-            alias: next().expect("string"),
-            description: next().expect("string"),
-        }
+        let required = |value: Option<String>, field: &str| {
+            value.ok_or_else(|| format!("Expected a string in the `{}` field", field))
+        };
+        let name = required(next()?, "name").map(Grammeme::new)?;
+        let parent = next()?.map(Grammeme::new);
+        let alias = required(next()?, "alias")?;
+        let description = required(next()?, "description")?;
+        Ok(GrammemeReg {
+            name,
+            parent,
+            alias,
+            description,
+        })
     }
 }
@@ -1,21 +1,63 @@
 use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
 
-use crate::opencorpora::grammeme::Grammeme;
+use crate::opencorpora::grammeme::{Grammeme, InvalidGrammeme};
 
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct GrammemeSet {
     pub set: HashSet<Grammeme>,
 }
 
-regex!(SEP, r"(,| )");
-
 impl GrammemeSet {
     pub fn new<S>(s: S) -> Self
     where
         S: AsRef<str>,
     {
         GrammemeSet {
-            set: SEP.split(s.as_ref()).map(Grammeme::new).collect(),
+            set: s
+                .as_ref()
+                .split(&[',', ' '][..])
+                .map(Grammeme::new)
+                .collect(),
+        }
+    }
+}
+
+/// Grammemes sorted alphabetically and separated by commas.
+impl fmt::Display for GrammemeSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut grammemes: Vec<&str> = self.set.iter().map(Grammeme::as_str).collect();
+        grammemes.sort_unstable();
+        f.write_str(&grammemes.join(","))
+    }
+}
+
+/// Grammemes separated by commas or spaces, like in tags;
+/// an empty string is an empty set.
+///
+/// ```
+/// use rsmorphy::GrammemeSet;
+///
+/// let set: GrammemeSet = "sing,nomn NOUN".parse().unwrap();
+/// assert_eq!(set, GrammemeSet::new("NOUN,sing,nomn"));
+/// assert_eq!(set.to_string(), "NOUN,nomn,sing");
+/// assert!("NOUN,,sing".parse::<GrammemeSet>().is_err());
+///
+/// let empty = GrammemeSet::default();
+/// assert_eq!(empty.to_string().parse(), Ok(empty));
+/// ```
+impl FromStr for GrammemeSet {
+    type Err = InvalidGrammeme;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Ok(GrammemeSet::default());
         }
+        let set = s
+            .split(&[',', ' '][..])
+            .map(Grammeme::from_str)
+            .collect::<Result<_, _>>()?;
+        Ok(GrammemeSet { set })
     }
 }
@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+use crate::opencorpora::grammeme::{Grammeme, GrammemeReg};
+use crate::opencorpora::kind::GrammemeKind;
+
+/// Categories of grammemes known to a dictionary.
+///
+/// A grammeme which is unknown to rsmorphy is taken for the nearest
+/// known grammeme among its parents, like a variety of a case
+/// is taken for the case.
+#[derive(Debug, Default, Clone)]
+pub struct GrammemeTable {
+    kinds: HashMap<String, GrammemeKind>,
+}
+
+impl GrammemeTable {
+    pub fn new(grammemes: &HashMap<Grammeme, GrammemeReg>) -> Self {
+        let kind_of = |grammeme: &Grammeme| {
+            let mut grammeme = Some(grammeme);
+            // Guards against cycles in broken dictionaries.
+            for _ in 0..grammemes.len() {
+                let reg = grammemes.get(grammeme?)?;
+                if let Some(kind) = GrammemeKind::from_grammeme(reg.name.as_str()) {
+                    return Some(kind);
+                }
+                grammeme = reg.parent.as_ref();
+            }
+            None
+        };
+        let kinds = grammemes
+            .keys()
+            .filter_map(|grammeme| Some((grammeme.as_str().to_owned(), kind_of(grammeme)?)))
+            .collect();
+        GrammemeTable { kinds }
+    }
+
+    /// Grammemes missing in the dictionary, like the ones of the analyzer
+    /// units, are looked up among the ones known to rsmorphy.
+    pub fn get(&self, grammeme: &str) -> Option<GrammemeKind> {
+        match self.kinds.get(grammeme) {
+            Some(&kind) => Some(kind),
+            None => GrammemeKind::from_grammeme(grammeme),
+        }
+    }
+}
@@ -6,31 +6,25 @@ pub enum Animacy {
     Inan,
 }
 
-regex!(
-    TAG_RE,
-    r"(?x)
-    (
-         anim
-        |inan
-    )
-"
-);
-
 impl Animacy {
+    /// Finds the grammeme of the category among grammemes of the tag.
     pub fn try_from_str<S>(s: S) -> Option<Self>
     where
         S: AsRef<str>,
     {
+        s.as_ref()
+            .split(&[',', ' '][..])
+            .find_map(Self::from_grammeme)
+    }
+
+    pub fn from_grammeme(grammeme: &str) -> Option<Self> {
         use self::Animacy::*;
 
-        TAG_RE
-            .captures_iter(s.as_ref())
-            .next()
-            .and_then(|cap| match &cap[1] {
-                "anim" => Some(Anim),
-                "inan" => Some(Inan),
-                _ => None,
-            })
+        match grammeme {
+            "anim" => Some(Anim),
+            "inan" => Some(Inan),
+            _ => None,
+        }
     }
 
     pub fn title_rus(self) -> &'static str {
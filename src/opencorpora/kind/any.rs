@@ -0,0 +1,44 @@
+use crate::opencorpora::kind::*;
+
+/// A grammeme of any category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GrammemeKind {
+    Pos(PartOfSpeach),
+    Animacy(Animacy),
+    Aspect(Aspect),
+    Case(Case),
+    Gender(Gender),
+    Involvement(Involvement),
+    Mood(Mood),
+    Number(Number),
+    Person(Person),
+    Tense(Tense),
+    Transitivity(Transitivity),
+    Voice(Voice),
+    Flag(Flag),
+}
+
+impl GrammemeKind {
+    /// ```
+    /// use rsmorphy::opencorpora::kind::{Case, GrammemeKind};
+    ///
+    /// assert_eq!(GrammemeKind::from_grammeme("gent"), Some(GrammemeKind::Case(Case::Gent)));
+    /// assert_eq!(GrammemeKind::from_grammeme("gen"), None);
+    /// ```
+    pub fn from_grammeme(grammeme: &str) -> Option<Self> {
+        PartOfSpeach::from_grammeme(grammeme)
+            .map(GrammemeKind::Pos)
+            .or_else(|| Animacy::from_grammeme(grammeme).map(GrammemeKind::Animacy))
+            .or_else(|| Aspect::from_grammeme(grammeme).map(GrammemeKind::Aspect))
+            .or_else(|| Case::from_grammeme(grammeme).map(GrammemeKind::Case))
+            .or_else(|| Gender::from_grammeme(grammeme).map(GrammemeKind::Gender))
+            .or_else(|| Involvement::from_grammeme(grammeme).map(GrammemeKind::Involvement))
+            .or_else(|| Mood::from_grammeme(grammeme).map(GrammemeKind::Mood))
+            .or_else(|| Number::from_grammeme(grammeme).map(GrammemeKind::Number))
+            .or_else(|| Person::from_grammeme(grammeme).map(GrammemeKind::Person))
+            .or_else(|| Tense::from_grammeme(grammeme).map(GrammemeKind::Tense))
+            .or_else(|| Transitivity::from_grammeme(grammeme).map(GrammemeKind::Transitivity))
+            .or_else(|| Voice::from_grammeme(grammeme).map(GrammemeKind::Voice))
+            .or_else(|| Flag::from_grammeme(grammeme).map(GrammemeKind::Flag))
+    }
+}
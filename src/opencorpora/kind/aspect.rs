@@ -6,31 +6,25 @@ pub enum Aspect {
     Impf,
 }
 
-regex!(
-    TAG_RE,
-    r"(?x)
-    (
-         perf
-        |impf
-    )
-"
-);
-
 impl Aspect {
+    /// Finds the grammeme of the category among grammemes of the tag.
     pub fn try_from_str<S>(s: S) -> Option<Self>
     where
         S: AsRef<str>,
     {
+        s.as_ref()
+            .split(&[',', ' '][..])
+            .find_map(Self::from_grammeme)
+    }
+
+    pub fn from_grammeme(grammeme: &str) -> Option<Self> {
         use self::Aspect::*;
 
-        TAG_RE
-            .captures_iter(s.as_ref())
-            .next()
-            .and_then(|cap| match &cap[1] {
-                "perf" => Some(Perf),
-                "impf" => Some(Impf),
-                _ => None,
-            })
+        match grammeme {
+            "perf" => Some(Perf),
+            "impf" => Some(Impf),
+            _ => None,
+        }
     }
 
     pub fn title_rus(self) -> &'static str {
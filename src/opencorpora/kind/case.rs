@@ -29,50 +29,35 @@ pub enum Case {
     Loc2,
 }
 
-regex!(
-    TAG_RE,
-    r"(?x)
-    (
-         nomn
-        |gent
-        |datv
-        |accs
-        |ablt
-        |loct
-        |voct
-        |gen1
-        |gen2
-        |acc2
-        |loc1
-        |loc2
-    )
-"
-);
-
 impl Case {
+    /// Finds the grammeme of the category among grammemes of the tag.
     pub fn try_from_str<S>(s: S) -> Option<Self>
     where
         S: AsRef<str>,
     {
+        s.as_ref()
+            .split(&[',', ' '][..])
+            .find_map(Self::from_grammeme)
+    }
+
+    pub fn from_grammeme(grammeme: &str) -> Option<Self> {
         use self::Case::*;
-        TAG_RE
-            .captures_iter(s.as_ref())
-            .next()
-            .and_then(|cap| match &cap[1] {
-                "nomn" => Some(Nomn),
-                "gent" => Some(Gent),
-                "datv" => Some(Datv),
-                "accs" => Some(Accs),
-                "ablt" => Some(Ablt),
-                "loct" => Some(Loct),
-                "voct" => Some(Voct),
-                "gen1" => Some(Gen1),
-                "gen2" => Some(Gen2),
-                "acc2" => Some(Acc2),
-                "loc1" => Some(Loc1),
-                "loc2" => Some(Loc2),
-                _ => None,
-            })
+
+        match grammeme {
+            "nomn" => Some(Nomn),
+            "gent" => Some(Gent),
+            "datv" => Some(Datv),
+            "accs" => Some(Accs),
+            "ablt" => Some(Ablt),
+            "loct" => Some(Loct),
+            "voct" => Some(Voct),
+            "gen1" => Some(Gen1),
+            "gen2" => Some(Gen2),
+            "acc2" => Some(Acc2),
+            "loc1" => Some(Loc1),
+            "loc2" => Some(Loc2),
+            _ => None,
+        }
     }
 
     pub fn to_grammeme(self) -> Grammeme {
@@ -113,3 +98,9 @@ impl Case {
         }
     }
 }
+
+impl From<Case> for Grammeme {
+    fn from(case: Case) -> Self {
+        case.to_grammeme()
+    }
+}
@@ -0,0 +1,186 @@
+use std::fmt;
+use std::iter::FromIterator;
+
+use crate::opencorpora::Grammeme;
+
+/// Groups of flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FlagGroup {
+    /// Имена собственные
+    Proper,
+    /// Число, которое не меняется
+    Tantum,
+    /// Разряды прилагательных
+    AdjectiveKind,
+    /// Свойства глаголов
+    VerbKind,
+    /// Стилистические пометы
+    Style,
+    /// Варианты форм
+    Variant,
+    /// Прочие пометы
+    Other,
+}
+
+macro_rules! flags {
+    ($($flag:ident = $grammeme:expr, $group:ident, $title:expr;)*) => {
+        /// Grammemes which don't belong to any category, like "Surn" or "Erro".
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum Flag {
+            $(#[doc = $title] $flag,)*
+        }
+
+        impl Flag {
+            pub const ALL: &'static [Flag] = &[$(Flag::$flag,)*];
+
+            pub fn from_grammeme(s: &str) -> Option<Self> {
+                match s {
+                    $($grammeme => Some(Flag::$flag),)*
+                    _ => None,
+                }
+            }
+
+            pub fn as_str(self) -> &'static str {
+                match self {
+                    $(Flag::$flag => $grammeme,)*
+                }
+            }
+
+            pub fn group(self) -> FlagGroup {
+                match self {
+                    $(Flag::$flag => FlagGroup::$group,)*
+                }
+            }
+
+            pub fn title_rus(self) -> &'static str {
+                match self {
+                    $(Flag::$flag => $title,)*
+                }
+            }
+        }
+    };
+}
+
+flags! {
+    MsF = "Ms-f", Other, "общий род";
+    Sgtm = "Sgtm", Tantum, "singularia tantum";
+    Pltm = "Pltm", Tantum, "pluralia tantum";
+    Fixd = "Fixd", Other, "неизменяемое";
+    Abbr = "Abbr", Other, "аббревиатура";
+    Name = "Name", Proper, "имя";
+    Surn = "Surn", Proper, "фамилия";
+    Patr = "Patr", Proper, "отчество";
+    Geox = "Geox", Proper, "топоним";
+    Orgn = "Orgn", Proper, "организация";
+    Trad = "Trad", Proper, "торговая марка";
+    Subx = "Subx", AdjectiveKind, "возможна субстантивация";
+    Supr = "Supr", AdjectiveKind, "превосходная степень";
+    Qual = "Qual", AdjectiveKind, "качественное";
+    Apro = "Apro", AdjectiveKind, "местоименное";
+    Anum = "Anum", AdjectiveKind, "порядковое";
+    Poss = "Poss", AdjectiveKind, "притяжательное";
+    VEy = "V-ey", Variant, "форма на -ею";
+    VOy = "V-oy", Variant, "форма на -ою";
+    Cmp2 = "Cmp2", Variant, "сравнительная степень на по-";
+    VEj = "V-ej", Variant, "форма компаратива на -ей";
+    Impe = "Impe", VerbKind, "безличный";
+    Impx = "Impx", VerbKind, "возможно безличное употребление";
+    Mult = "Mult", VerbKind, "многократный";
+    Refl = "Refl", VerbKind, "возвратный";
+    Infr = "Infr", Style, "разговорное";
+    Slng = "Slng", Style, "жаргонное";
+    Arch = "Arch", Style, "устаревшее";
+    Litr = "Litr", Style, "литературный вариант";
+    Erro = "Erro", Style, "опечатка";
+    Dist = "Dist", Style, "искажение";
+    Ques = "Ques", Other, "вопросительное";
+    Dmns = "Dmns", Other, "указательное";
+    Prnt = "Prnt", Other, "вводное слово";
+    VBe = "V-be", Variant, "форма на -ье";
+    VEn = "V-en", Variant, "форма на -енен";
+    VIe = "V-ie", Variant, "отчество через -ие-";
+    VBi = "V-bi", Variant, "форма на -ьи";
+    Fimp = "Fimp", VerbKind, "деепричастие от глагола несовершенного вида";
+    Prdx = "Prdx", Other, "может выступать в роли предикатива";
+    Coun = "Coun", Other, "счётная форма";
+    Coll = "Coll", Other, "собирательное числительное";
+    VSh = "V-sh", Variant, "деепричастие на -ши";
+    AfP = "Af-p", Variant, "форма после предлога";
+    Inmx = "Inmx", Other, "может использоваться как одуш. / неодуш.";
+    Vpre = "Vpre", Variant, "вариант предлога (со, подо, ...)";
+    Anph = "Anph", Other, "анафорическое (местоимение)";
+    Init = "Init", Proper, "инициал";
+    Adjx = "Adjx", Other, "может выступать в роли прилагательного";
+}
+
+impl From<Flag> for Grammeme {
+    fn from(flag: Flag) -> Self {
+        Grammeme::new(flag.as_str())
+    }
+}
+
+/// A set of flags.
+///
+/// ```
+/// use rsmorphy::opencorpora::kind::{Flag, FlagGroup, Flags};
+///
+/// let flags: Flags = vec![Flag::Surn, Flag::Infr].into_iter().collect();
+/// assert!(flags.contains(Flag::Surn));
+/// assert!(!flags.contains(Flag::Name));
+/// assert!(flags.intersects(FlagGroup::Style));
+/// assert_eq!(flags.iter().collect::<Vec<_>>(), vec![Flag::Surn, Flag::Infr]);
+/// ```
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Flags {
+    bits: u64,
+}
+
+impl Flags {
+    pub fn new() -> Self {
+        Flags::default()
+    }
+
+    pub fn insert(&mut self, flag: Flag) {
+        self.bits |= 1 << flag as u64;
+    }
+
+    pub fn remove(&mut self, flag: Flag) {
+        self.bits &= !(1 << flag as u64);
+    }
+
+    pub fn contains(self, flag: Flag) -> bool {
+        self.bits & (1 << flag as u64) != 0
+    }
+
+    /// Checks if any flag of the group is set.
+    pub fn intersects(self, group: FlagGroup) -> bool {
+        self.iter().any(|flag| flag.group() == group)
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = Flag> {
+        Flag::ALL
+            .iter()
+            .cloned()
+            .filter(move |&flag| self.contains(flag))
+    }
+}
+
+impl FromIterator<Flag> for Flags {
+    fn from_iter<I: IntoIterator<Item = Flag>>(iter: I) -> Self {
+        let mut flags = Flags::new();
+        for flag in iter {
+            flags.insert(flag);
+        }
+        flags
+    }
+}
+
+impl fmt::Debug for Flags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
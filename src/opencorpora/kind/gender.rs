@@ -9,32 +9,26 @@ pub enum Gender {
     Neut,
 }
 
-regex!(
-    TAG_RE,
-    r"(?x)
-    (
-         masc
-        |femn
-        |neut
-    )
-"
-);
-
 impl Gender {
+    /// Finds the grammeme of the category among grammemes of the tag.
     pub fn try_from_str<S>(s: S) -> Option<Self>
     where
         S: AsRef<str>,
     {
+        s.as_ref()
+            .split(&[',', ' '][..])
+            .find_map(Self::from_grammeme)
+    }
+
+    pub fn from_grammeme(grammeme: &str) -> Option<Self> {
         use self::Gender::*;
-        TAG_RE
-            .captures_iter(s.as_ref())
-            .next()
-            .and_then(|cap| match &cap[1] {
-                "masc" => Some(Masc),
-                "femn" => Some(Femn),
-                "neut" => Some(Neut),
-                _ => None,
-            })
+
+        match grammeme {
+            "masc" => Some(Masc),
+            "femn" => Some(Femn),
+            "neut" => Some(Neut),
+            _ => None,
+        }
     }
 
     pub fn title_rus(self) -> &'static str {
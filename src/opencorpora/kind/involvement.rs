@@ -6,30 +6,25 @@ pub enum Involvement {
     Excl,
 }
 
-regex!(
-    TAG_RE,
-    r"(?x)
-    (
-         incl
-        |excl
-    )
-"
-);
-
 impl Involvement {
+    /// Finds the grammeme of the category among grammemes of the tag.
     pub fn try_from_str<S>(s: S) -> Option<Self>
     where
         S: AsRef<str>,
     {
+        s.as_ref()
+            .split(&[',', ' '][..])
+            .find_map(Self::from_grammeme)
+    }
+
+    pub fn from_grammeme(grammeme: &str) -> Option<Self> {
         use self::Involvement::*;
-        TAG_RE
-            .captures_iter(s.as_ref())
-            .next()
-            .and_then(|cap| match &cap[1] {
-                "incl" => Some(Incl),
-                "excl" => Some(Excl),
-                _ => None,
-            })
+
+        match grammeme {
+            "incl" => Some(Incl),
+            "excl" => Some(Excl),
+            _ => None,
+        }
     }
 
     pub fn title_rus(self) -> &'static str {
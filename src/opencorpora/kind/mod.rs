@@ -1,6 +1,8 @@
 pub mod animacy;
+pub mod any;
 pub mod aspect;
 pub mod case;
+pub mod flag;
 pub mod gender;
 pub mod involvement;
 pub mod mood;
@@ -12,8 +14,10 @@ pub mod transitivity;
 pub mod voice;
 
 pub use self::animacy::Animacy;
+pub use self::any::GrammemeKind;
 pub use self::aspect::Aspect;
 pub use self::case::Case;
+pub use self::flag::{Flag, FlagGroup, Flags};
 pub use self::gender::Gender;
 pub use self::involvement::Involvement;
 pub use self::mood::Mood;
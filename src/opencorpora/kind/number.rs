@@ -9,30 +9,25 @@ pub enum Number {
     Plur,
 }
 
-regex!(
-    TAG_RE,
-    r"(?x)
-    (
-         sing
-        |plur
-    )
-"
-);
-
 impl Number {
+    /// Finds the grammeme of the category among grammemes of the tag.
     pub fn try_from_str<S>(s: S) -> Option<Self>
     where
         S: AsRef<str>,
     {
+        s.as_ref()
+            .split(&[',', ' '][..])
+            .find_map(Self::from_grammeme)
+    }
+
+    pub fn from_grammeme(grammeme: &str) -> Option<Self> {
         use self::Number::*;
-        TAG_RE
-            .captures_iter(s.as_ref())
-            .next()
-            .and_then(|cap| match &cap[1] {
-                "sing" => Some(Sing),
-                "plur" => Some(Plur),
-                _ => None,
-            })
+
+        match grammeme {
+            "sing" => Some(Sing),
+            "plur" => Some(Plur),
+            _ => None,
+        }
     }
 
     pub fn to_grammeme(self) -> Grammeme {
@@ -52,3 +47,9 @@ impl Number {
         }
     }
 }
+
+impl From<Number> for Grammeme {
+    fn from(number: Number) -> Self {
+        number.to_grammeme()
+    }
+}
@@ -8,32 +8,26 @@ pub enum Person {
     Per3,
 }
 
-regex!(
-    TAG_RE,
-    r"(?x)
-    (
-         1per
-        |2per
-        |3per
-    )
-"
-);
-
 impl Person {
+    /// Finds the grammeme of the category among grammemes of the tag.
     pub fn try_from_str<S>(s: S) -> Option<Self>
     where
         S: AsRef<str>,
     {
+        s.as_ref()
+            .split(&[',', ' '][..])
+            .find_map(Self::from_grammeme)
+    }
+
+    pub fn from_grammeme(grammeme: &str) -> Option<Self> {
         use self::Person::*;
-        TAG_RE
-            .captures_iter(s.as_ref())
-            .next()
-            .and_then(|cap| match &cap[1] {
-                "1per" => Some(Per1),
-                "2per" => Some(Per2),
-                "3per" => Some(Per3),
-                _ => None,
-            })
+
+        match grammeme {
+            "1per" => Some(Per1),
+            "2per" => Some(Per2),
+            "3per" => Some(Per3),
+            _ => None,
+        }
     }
 
     pub fn title_rus(self) -> &'static str {
@@ -36,61 +36,40 @@ pub enum PartOfSpeach {
     Intj,
 }
 
-regex!(
-    TAG_RE,
-    r"(?x)
-    (
-         NOUN
-        |ADJF
-        |ADJS
-        |COMP
-        |VERB
-        |INFN
-        |PRTF
-        |PRTS
-        |GRND
-        |NUMR
-        |ADVB
-        |NPRO
-        |PRED
-        |PREP
-        |CONJ
-        |PRCL
-        |INTJ
-    )
-"
-);
-
 impl PartOfSpeach {
+    /// Finds the grammeme of the category among grammemes of the tag.
     pub fn try_from_str<S>(s: S) -> Option<Self>
     where
         S: AsRef<str>,
     {
+        s.as_ref()
+            .split(&[',', ' '][..])
+            .find_map(Self::from_grammeme)
+    }
+
+    pub fn from_grammeme(grammeme: &str) -> Option<Self> {
         use self::PartOfSpeach::*;
 
-        TAG_RE
-            .captures_iter(s.as_ref())
-            .next()
-            .and_then(|cap| match &cap[1] {
-                "NOUN" => Some(Noun),
-                "ADJF" => Some(Adjf),
-                "ADJS" => Some(Adjs),
-                "COMP" => Some(Comp),
-                "VERB" => Some(Verb),
-                "INFN" => Some(Infn),
-                "PRTF" => Some(Prtf),
-                "PRTS" => Some(Prts),
-                "GRND" => Some(Grnd),
-                "NUMR" => Some(Numr),
-                "ADVB" => Some(Advb),
-                "NPRO" => Some(Npro),
-                "PRED" => Some(Pred),
-                "PREP" => Some(Prep),
-                "CONJ" => Some(Conj),
-                "PRCL" => Some(Prcl),
-                "INTJ" => Some(Intj),
-                _ => None,
-            })
+        match grammeme {
+            "NOUN" => Some(Noun),
+            "ADJF" => Some(Adjf),
+            "ADJS" => Some(Adjs),
+            "COMP" => Some(Comp),
+            "VERB" => Some(Verb),
+            "INFN" => Some(Infn),
+            "PRTF" => Some(Prtf),
+            "PRTS" => Some(Prts),
+            "GRND" => Some(Grnd),
+            "NUMR" => Some(Numr),
+            "ADVB" => Some(Advb),
+            "NPRO" => Some(Npro),
+            "PRED" => Some(Pred),
+            "PREP" => Some(Prep),
+            "CONJ" => Some(Conj),
+            "PRCL" => Some(Prcl),
+            "INTJ" => Some(Intj),
+            _ => None,
+        }
     }
 
     pub fn is_productive(self) -> bool {
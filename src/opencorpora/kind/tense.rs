@@ -8,32 +8,26 @@ pub enum Tense {
     Futr,
 }
 
-regex!(
-    TAG_RE,
-    r"(?x)
-    (
-         pres
-        |past
-        |futr
-    )
-"
-);
-
 impl Tense {
+    /// Finds the grammeme of the category among grammemes of the tag.
     pub fn try_from_str<S>(s: S) -> Option<Self>
     where
         S: AsRef<str>,
     {
+        s.as_ref()
+            .split(&[',', ' '][..])
+            .find_map(Self::from_grammeme)
+    }
+
+    pub fn from_grammeme(grammeme: &str) -> Option<Self> {
         use self::Tense::*;
-        TAG_RE
-            .captures_iter(s.as_ref())
-            .next()
-            .and_then(|cap| match &cap[1] {
-                "pres" => Some(Pres),
-                "past" => Some(Past),
-                "futr" => Some(Futr),
-                _ => None,
-            })
+
+        match grammeme {
+            "pres" => Some(Pres),
+            "past" => Some(Past),
+            "futr" => Some(Futr),
+            _ => None,
+        }
     }
 
     pub fn title_rus(self) -> &'static str {
@@ -6,30 +6,25 @@ pub enum Transitivity {
     Intr,
 }
 
-regex!(
-    TAG_RE,
-    r"(?x)
-    (
-         tran
-        |intr
-    )
-"
-);
-
 impl Transitivity {
+    /// Finds the grammeme of the category among grammemes of the tag.
     pub fn try_from_str<S>(s: S) -> Option<Self>
     where
         S: AsRef<str>,
     {
+        s.as_ref()
+            .split(&[',', ' '][..])
+            .find_map(Self::from_grammeme)
+    }
+
+    pub fn from_grammeme(grammeme: &str) -> Option<Self> {
         use self::Transitivity::*;
-        TAG_RE
-            .captures_iter(s.as_ref())
-            .next()
-            .and_then(|cap| match &cap[1] {
-                "tran" => Some(Tran),
-                "intr" => Some(Intr),
-                _ => None,
-            })
+
+        match grammeme {
+            "tran" => Some(Tran),
+            "intr" => Some(Intr),
+            _ => None,
+        }
     }
 
     pub fn title_rus(self) -> &'static str {
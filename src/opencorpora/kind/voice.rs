@@ -7,30 +7,25 @@ pub enum Voice {
     Pssv,
 }
 
-regex!(
-    TAG_RE,
-    r"(?x)
-    (
-         actv
-        |pssv
-    )
-"
-);
-
 impl Voice {
+    /// Finds the grammeme of the category among grammemes of the tag.
     pub fn try_from_str<S>(s: S) -> Option<Self>
     where
         S: AsRef<str>,
     {
+        s.as_ref()
+            .split(&[',', ' '][..])
+            .find_map(Self::from_grammeme)
+    }
+
+    pub fn from_grammeme(grammeme: &str) -> Option<Self> {
         use self::Voice::*;
-        TAG_RE
-            .captures_iter(s.as_ref())
-            .next()
-            .and_then(|cap| match &cap[1] {
-                "actv" => Some(Actv),
-                "pssv" => Some(Pssv),
-                _ => None,
-            })
+
+        match grammeme {
+            "actv" => Some(Actv),
+            "pssv" => Some(Pssv),
+            _ => None,
+        }
     }
 
     pub fn title_rus(self) -> &'static str {
@@ -1,9 +1,15 @@
 pub mod dictionary;
+pub mod error;
 pub mod grammeme;
 pub mod kind;
+pub mod msd;
 pub mod paradigm;
+pub mod query;
 pub mod tag;
+pub mod ud;
 
 pub use self::dictionary::Dictionary;
-pub use self::grammeme::{Grammeme, GrammemeSet};
-pub use self::tag::OpencorporaTagReg;
+pub use self::error::DictionaryError;
+pub use self::grammeme::{Grammeme, GrammemeBits, GrammemeSet};
+pub use self::tag::{OpencorporaTagReg, Tagset};
+pub use self::ud::UdTag;
@@ -0,0 +1,442 @@
+//! Conversion between OpenCorpora tags and MULTEXT-East Russian MSD strings
+//! like "Ncmsnn".
+//!
+//! The conversion is lossy both ways, so the parts which don't survive it
+//! are reported along with the result.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::opencorpora::kind::Flag;
+use crate::opencorpora::{Grammeme, OpencorporaTagReg};
+
+/// A value of an MSD position: its code, the grammeme it's taken for
+/// (`""` if it's taken for any tag of the category) and the grammeme
+/// it yields back (`Some("")` if none is needed, `None` if there's no such).
+type Value = (char, &'static str, Option<&'static str>);
+
+/// An MSD position: the attribute name and its values; the first value
+/// of a code is the one it's read as.
+type Attribute = (&'static str, &'static [Value]);
+
+const GENDER: Attribute = (
+    "Gender",
+    &[
+        ('m', "masc", Some("masc")),
+        ('f', "femn", Some("femn")),
+        ('n', "neut", Some("neut")),
+        ('c', "Ms-f", Some("Ms-f")),
+    ],
+);
+const NUMBER: Attribute = (
+    "Number",
+    &[('s', "sing", Some("sing")), ('p', "plur", Some("plur"))],
+);
+const CASE: Attribute = (
+    "Case",
+    &[
+        ('n', "nomn", Some("nomn")),
+        ('g', "gent", Some("gent")),
+        ('g', "gen1", Some("gent")),
+        ('g', "gen2", Some("gent")),
+        ('d', "datv", Some("datv")),
+        ('a', "accs", Some("accs")),
+        ('a', "acc2", Some("accs")),
+        ('v', "voct", Some("voct")),
+        ('l', "loct", Some("loct")),
+        ('l', "loc1", Some("loct")),
+        ('l', "loc2", Some("loct")),
+        ('i', "ablt", Some("ablt")),
+    ],
+);
+const ANIMATE: Attribute = (
+    "Animate",
+    &[('y', "anim", Some("anim")), ('n', "inan", Some("inan"))],
+);
+const PERSON: Attribute = (
+    "Person",
+    &[
+        ('1', "1per", Some("1per")),
+        ('2', "2per", Some("2per")),
+        ('3', "3per", Some("3per")),
+    ],
+);
+
+const NOUN: &[Attribute] = &[
+    (
+        "Type",
+        &[
+            ('p', "Name", None),
+            ('p', "Surn", None),
+            ('p', "Patr", None),
+            ('p', "Geox", None),
+            ('p', "Orgn", None),
+            ('p', "Trad", None),
+            ('c', "", Some("")),
+        ],
+    ),
+    GENDER,
+    NUMBER,
+    CASE,
+    ANIMATE,
+];
+const VERB: &[Attribute] = &[
+    ("Type", &[('m', "", Some(""))]),
+    (
+        "VForm",
+        &[
+            ('i', "indc", Some("indc")),
+            ('m', "impr", Some("impr")),
+            ('n', "INFN", Some("INFN")),
+            ('p', "PRTF", Some("PRTF")),
+            ('p', "PRTS", Some("PRTF")),
+            ('g', "GRND", Some("GRND")),
+        ],
+    ),
+    (
+        "Tense",
+        &[
+            ('p', "pres", Some("pres")),
+            ('f', "futr", Some("futr")),
+            ('s', "past", Some("past")),
+        ],
+    ),
+    PERSON,
+    NUMBER,
+    GENDER,
+    (
+        "Voice",
+        &[('a', "actv", Some("actv")), ('p', "pssv", Some("pssv"))],
+    ),
+    (
+        "Definiteness",
+        &[('s', "PRTS", Some("PRTS")), ('f', "PRTF", Some(""))],
+    ),
+    (
+        "Aspect",
+        &[('p', "impf", Some("impf")), ('e', "perf", Some("perf"))],
+    ),
+    CASE,
+];
+const ADJECTIVE: &[Attribute] = &[
+    ("Type", &[('s', "Poss", Some("Poss")), ('f', "", Some(""))]),
+    (
+        "Degree",
+        &[
+            ('c', "COMP", Some("COMP")),
+            ('s', "Supr", Some("Supr")),
+            ('p', "", Some("")),
+        ],
+    ),
+    GENDER,
+    NUMBER,
+    CASE,
+    (
+        "Definiteness",
+        &[('s', "ADJS", Some("ADJS")), ('f', "ADJF", Some(""))],
+    ),
+];
+const PRONOUN: &[Attribute] = &[
+    ("Type", &[]),
+    PERSON,
+    GENDER,
+    NUMBER,
+    CASE,
+    ANIMATE,
+    (
+        "Syntactic_Type",
+        &[('a', "Apro", Some("Apro")), ('n', "", Some(""))],
+    ),
+];
+const ADPOSITION: &[Attribute] = &[("Type", &[('p', "", Some(""))]), ("Formation", &[]), CASE];
+const CONJUNCTION: &[Attribute] = &[("Type", &[]), ("Formation", &[])];
+const NUMERAL: &[Attribute] = &[
+    ("Type", &[('c', "", Some(""))]),
+    GENDER,
+    NUMBER,
+    CASE,
+    (
+        "Form",
+        &[
+            ('d', "NUMB", Some("NUMB")),
+            ('r', "ROMN", Some("ROMN")),
+            ('l', "", Some("")),
+        ],
+    ),
+];
+const RESIDUAL: &[Attribute] = &[("Type", &[('f', "LATN", Some("LATN"))])];
+
+/// MSD categories: the code, the part of speech it's read as by default
+/// and the positions.
+const CATEGORIES: &[(char, &str, &[Attribute])] = &[
+    ('N', "NOUN", NOUN),
+    ('V', "VERB", VERB),
+    ('A', "ADJF", ADJECTIVE),
+    ('P', "NPRO", PRONOUN),
+    ('R', "ADVB", &[]),
+    ('S', "PREP", ADPOSITION),
+    ('C', "CONJ", CONJUNCTION),
+    ('M', "NUMR", NUMERAL),
+    ('Q', "PRCL", &[]),
+    ('I', "INTJ", &[]),
+    ('X', "UNKN", RESIDUAL),
+];
+
+/// Grammemes which stand for parts of speech, including the ones
+/// of the analyzer units.
+const POS: &[&str] = &[
+    "NOUN", "ADJF", "ADJS", "COMP", "VERB", "INFN", "PRTF", "PRTS", "GRND", "NUMR", "ADVB", "NPRO",
+    "PRED", "PREP", "CONJ", "PRCL", "INTJ", "LATN", "PNCT", "NUMB", "ROMN", "UNKN",
+];
+
+/// Grammemes which belong to the lexeme rather than to the word form,
+/// for parts of speech which have them; they go before the space in a tag.
+const LEXEME_GRAMMEMES: &[(&str, &[&str])] = &[
+    ("NOUN", &["anim", "inan", "masc", "femn", "neut", "Ms-f"]),
+    (
+        "NPRO",
+        &["masc", "femn", "neut", "Ms-f", "1per", "2per", "3per"],
+    ),
+    ("PRTF", &["pres", "past", "futr", "actv", "pssv"]),
+    ("PRTS", &["pres", "past", "futr", "actv", "pssv"]),
+];
+
+/// Grammemes which belong to the lexeme for any part of speech.
+const LEXICAL: &[&str] = &["Apro", "Poss", "Supr", "perf", "impf"];
+
+/// The order of grammemes in OpenCorpora tags.
+const ORDER: &[&str] = &[
+    "Apro", "Poss", "Supr", "perf", "impf", "anim", "inan", "masc", "femn", "neut", "Ms-f", "sing",
+    "plur", "1per", "2per", "3per", "pres", "past", "futr", "indc", "impr", "actv", "pssv", "nomn",
+    "gent", "datv", "accs", "ablt", "loct", "voct",
+];
+
+/// A value of an MSD position with no OpenCorpora counterpart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MsdValue {
+    pub attribute: &'static str,
+    pub value: char,
+}
+
+/// The result of `OpencorporaTagReg::to_msd`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MsdExport {
+    pub msd: String,
+    /// Grammemes of the tag which are lost in `msd`.
+    pub unmapped: Vec<Grammeme>,
+}
+
+/// The result of `OpencorporaTagReg::from_msd`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MsdImport {
+    pub tag: OpencorporaTagReg,
+    /// Values of the MSD which are lost in `tag`.
+    pub unmapped: Vec<MsdValue>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MsdError {
+    /// The MSD is empty.
+    Empty,
+    /// The MSD starts with an unknown category.
+    UnknownCategory(char),
+    /// The MSD has more positions than its category.
+    TooLong { category: char, positions: usize },
+    /// The part of speech of the tag has no MSD category, like punctuation.
+    NoCategory(String),
+}
+
+impl fmt::Display for MsdError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MsdError::Empty => write!(f, "empty MSD"),
+            MsdError::UnknownCategory(category) => {
+                write!(f, "unknown MSD category {:?}", category)
+            }
+            MsdError::TooLong {
+                category,
+                positions,
+            } => write!(
+                f,
+                "MSD category {:?} has only {} positions",
+                category, positions
+            ),
+            MsdError::NoCategory(tag) => write!(f, "no MSD category for {:?}", tag),
+        }
+    }
+}
+
+impl Error for MsdError {}
+
+impl OpencorporaTagReg {
+    /// ```
+    /// use rsmorphy::opencorpora::{Grammeme, OpencorporaTagReg};
+    ///
+    /// let tag = OpencorporaTagReg::new("NOUN,inan,masc sing,nomn");
+    /// assert_eq!(tag.to_msd().unwrap().msd, "Ncmsnn");
+    ///
+    /// let tag = OpencorporaTagReg::new("VERB,perf,intr sing,3per,futr,indc");
+    /// let export = tag.to_msd().unwrap();
+    /// assert_eq!(export.msd, "Vmif3s---e");
+    /// assert_eq!(export.unmapped, vec![Grammeme::new("intr")]);
+    /// ```
+    pub fn to_msd(&self) -> Result<MsdExport, MsdError> {
+        let has = |grammeme: &str| self.has(grammeme);
+        let category = match self.grammemes_in_order().next() {
+            Some("NOUN") => 'N',
+            Some("VERB") | Some("INFN") | Some("PRTF") | Some("PRTS") | Some("GRND") => 'V',
+            Some("ADJF") if self.flags.contains(Flag::Apro) => 'P',
+            Some("ADJF") | Some("ADJS") | Some("COMP") => 'A',
+            Some("NPRO") => 'P',
+            Some("ADVB") | Some("PRED") => 'R',
+            Some("PREP") => 'S',
+            Some("CONJ") => 'C',
+            Some("NUMR") | Some("NUMB") | Some("ROMN") => 'M',
+            Some("PRCL") => 'Q',
+            Some("INTJ") => 'I',
+            Some("LATN") | Some("UNKN") => 'X',
+            _ => return Err(MsdError::NoCategory(self.string.clone())),
+        };
+        let attributes = category_attributes(category).expect("All categories are listed");
+
+        let mut msd = category.to_string();
+        let mut kept = Vec::new();
+        for &(_, values) in attributes {
+            let value = values
+                .iter()
+                .find(|&&(_, grammeme, _)| grammeme.is_empty() || has(grammeme));
+            match value {
+                Some(&(code, grammeme, reverse)) => {
+                    msd.push(code);
+                    if reverse == Some(grammeme) {
+                        kept.push(grammeme);
+                    }
+                }
+                None => msd.push('-'),
+            }
+        }
+        let len = msd.trim_end_matches('-').len();
+        msd.truncate(len);
+
+        let unmapped = self
+            .grammemes_in_order()
+            .filter(|grammeme| !POS.contains(grammeme) && !kept.contains(grammeme))
+            .map(Grammeme::new)
+            .collect();
+        Ok(MsdExport { msd, unmapped })
+    }
+
+    /// ```
+    /// use rsmorphy::opencorpora::msd::MsdValue;
+    /// use rsmorphy::opencorpora::OpencorporaTagReg;
+    ///
+    /// let import = OpencorporaTagReg::from_msd("Ncmsnn").unwrap();
+    /// assert_eq!(import.tag.string, "NOUN,inan,masc sing,nomn");
+    /// assert!(import.unmapped.is_empty());
+    ///
+    /// let import = OpencorporaTagReg::from_msd("Vmpp-smafp").unwrap();
+    /// assert_eq!(import.tag.string, "PRTF,impf,pres,actv masc,sing");
+    ///
+    /// let import = OpencorporaTagReg::from_msd("Npfsnn").unwrap();
+    /// assert_eq!(import.tag.string, "NOUN,inan,femn sing,nomn");
+    /// assert_eq!(import.unmapped, vec![MsdValue { attribute: "Type", value: 'p' }]);
+    /// ```
+    pub fn from_msd(msd: &str) -> Result<MsdImport, MsdError> {
+        let mut codes = msd.chars();
+        let category = codes.next().ok_or(MsdError::Empty)?;
+        let (mut pos, attributes) = CATEGORIES
+            .iter()
+            .find(|&&(code, _, _)| code == category)
+            .map(|&(_, pos, attributes)| (pos, attributes))
+            .ok_or(MsdError::UnknownCategory(category))?;
+        let codes: Vec<char> = codes.collect();
+        if codes.len() > attributes.len() {
+            return Err(MsdError::TooLong {
+                category,
+                positions: attributes.len(),
+            });
+        }
+
+        let mut grammemes = Vec::new();
+        let mut unmapped = Vec::new();
+        for (&code, &(attribute, values)) in codes.iter().zip(attributes) {
+            if code == '-' {
+                continue;
+            }
+            match values.iter().find(|&&(c, _, _)| c == code) {
+                Some(&(_, _, Some(""))) => {}
+                Some(&(_, _, Some(grammeme))) if POS.contains(&grammeme) => pos = grammeme,
+                Some(&(_, _, Some(grammeme))) => grammemes.push(grammeme),
+                Some(&(_, _, None)) | None => unmapped.push(MsdValue {
+                    attribute,
+                    value: code,
+                }),
+            }
+        }
+        if grammemes.contains(&"Apro") {
+            pos = "ADJF";
+        }
+
+        let tag = OpencorporaTagReg::new(join_tag(pos, &grammemes));
+        Ok(MsdImport { tag, unmapped })
+    }
+
+    fn grammemes_in_order(&self) -> impl Iterator<Item = &str> {
+        self.string.split(&[',', ' '][..])
+    }
+}
+
+fn category_attributes(category: char) -> Option<&'static [Attribute]> {
+    CATEGORIES
+        .iter()
+        .find(|&&(code, _, _)| code == category)
+        .map(|&(_, _, attributes)| attributes)
+}
+
+/// Spells grammemes as an OpenCorpora tag: the part of speech and grammemes
+/// of the lexeme, then the ones of the word form.
+fn join_tag(pos: &str, grammemes: &[&str]) -> String {
+    let lexeme = LEXEME_GRAMMEMES
+        .iter()
+        .find(|&&(p, _)| p == pos)
+        .map_or(&[][..], |&(_, lexeme)| lexeme);
+    let mut grammemes = grammemes.to_vec();
+    grammemes.sort_by_key(|grammeme| ORDER.iter().position(|g| g == grammeme));
+
+    let mut tag = pos.to_owned();
+    let is_lexical = |grammeme: &&str| LEXICAL.contains(grammeme) || lexeme.contains(grammeme);
+    for grammeme in grammemes.iter().filter(|g| is_lexical(g)) {
+        tag.push(',');
+        tag.push_str(grammeme);
+    }
+    let mut sep = ' ';
+    for grammeme in grammemes.iter().filter(|g| !is_lexical(g)) {
+        tag.push(sep);
+        tag.push_str(grammeme);
+        sep = ',';
+    }
+    tag
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opencorpora::Dictionary;
+
+    #[test]
+    fn round_trip() {
+        let dict = Dictionary::from_file(dict_ru::DICT_PATH);
+        for tag in dict.gramtab.iter() {
+            let export = tag.to_msd().unwrap();
+            let import = OpencorporaTagReg::from_msd(&export.msd).unwrap();
+            // Grammemes are either kept or reported, but the part of speech
+            // may turn into another one.
+            let mut lost = &tag.grammemes.set - &import.tag.grammemes.set;
+            for grammeme in export.unmapped {
+                lost.remove(&grammeme);
+            }
+            lost.remove(&Grammeme::new(tag.string.split(',').next().unwrap()));
+            assert!(lost.is_empty(), "{}: {:?}", tag.string, lost);
+        }
+    }
+}
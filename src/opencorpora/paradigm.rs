@@ -1,5 +1,9 @@
 use std::iter::FromIterator;
+use std::ops::Index;
 
+use crate::bundle::{Plain, Table};
+
+#[repr(C)]
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub struct ParadigmEntry {
     pub prefix_id: u16,
@@ -19,3 +23,66 @@ impl ParadigmEntry {
         }))
     }
 }
+
+unsafe impl Plain for ParadigmEntry {}
+
+/// All paradigms of a dictionary, stored as one flat table of entries.
+#[derive(Debug, Clone)]
+pub struct Paradigms {
+    /// `offsets[i]..offsets[i + 1]` is the range of the `i`-th paradigm
+    pub offsets: Table<u32>,
+    pub entries: Table<ParadigmEntry>,
+}
+
+impl Paradigms {
+    pub fn new<O, E>(offsets: O, entries: E) -> Self
+    where
+        O: Into<Table<u32>>,
+        E: Into<Table<ParadigmEntry>>,
+    {
+        let offsets = offsets.into();
+        let entries = entries.into();
+        Paradigms { offsets, entries }
+    }
+
+    pub fn len(&self) -> usize {
+        self.offsets.len().saturating_sub(1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, idx: usize) -> Option<&[ParadigmEntry]> {
+        let start = *self.offsets.get(idx)? as usize;
+        let end = *self.offsets.get(idx + 1)? as usize;
+        self.entries.get(start..end)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &[ParadigmEntry]> {
+        (0..self.len()).map(move |idx| &self[idx])
+    }
+}
+
+impl Index<usize> for Paradigms {
+    type Output = [ParadigmEntry];
+
+    fn index(&self, idx: usize) -> &[ParadigmEntry] {
+        self.get(idx).expect("paradigm index out of range")
+    }
+}
+
+impl FromIterator<Vec<ParadigmEntry>> for Paradigms {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Vec<ParadigmEntry>>,
+    {
+        let mut offsets = vec![0];
+        let mut entries = Vec::new();
+        for paradigm in iter {
+            entries.extend(paradigm);
+            offsets.push(entries.len() as u32);
+        }
+        Paradigms::new(offsets, entries)
+    }
+}
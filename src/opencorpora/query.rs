@@ -0,0 +1,282 @@
+//! Boolean expressions over grammemes for filtering tags, like
+//! "NOUN & anim & (nomn | accs) & !plur".
+//!
+//! `&` binds tighter than `|`, and `!` binds tighter than both.
+
+use std::error::Error;
+use std::fmt;
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+use crate::opencorpora::grammeme::grammeme::is_grammeme_char;
+use crate::opencorpora::{Dictionary, Grammeme, OpencorporaTagReg};
+
+/// How deep `!` and parentheses may be nested in a query; deeper queries
+/// are rejected rather than overflowing the stack.
+pub const MAX_DEPTH: usize = 64;
+
+/// A parsed query, see `OpencorporaTagReg::matches`.
+///
+/// ```
+/// use rsmorphy::opencorpora::query::TagQuery;
+/// use rsmorphy::opencorpora::OpencorporaTagReg;
+///
+/// let query = TagQuery::parse("NOUN & anim & (nomn | accs) & !plur").unwrap();
+/// assert!(OpencorporaTagReg::new("NOUN,anim,masc sing,accs").matches(&query));
+/// assert!(!OpencorporaTagReg::new("NOUN,anim,masc plur,nomn").matches(&query));
+/// assert!(!OpencorporaTagReg::new("NOUN,inan,masc sing,nomn").matches(&query));
+/// assert_eq!(query.to_string(), "NOUN & anim & (nomn | accs) & !plur");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TagQuery {
+    Grammeme(Grammeme),
+    Not(Box<TagQuery>),
+    And(Vec<TagQuery>),
+    Or(Vec<TagQuery>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TagQueryError {
+    /// The query ends where a grammeme or a parenthesized query is expected.
+    UnexpectedEnd,
+    /// The query has an unexpected character at the byte offset.
+    UnexpectedChar { pos: usize, ch: char },
+    /// The grammeme is unknown to the dictionary.
+    UnknownGrammeme(String),
+    /// `!` and parentheses are nested deeper than `MAX_DEPTH`
+    /// at the byte offset.
+    TooDeep { pos: usize },
+}
+
+impl fmt::Display for TagQueryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TagQueryError::UnexpectedEnd => write!(f, "unexpected end of the query"),
+            TagQueryError::UnexpectedChar { pos, ch } => {
+                write!(f, "unexpected {:?} at {} in the query", ch, pos)
+            }
+            TagQueryError::UnknownGrammeme(grammeme) => {
+                write!(f, "unknown grammeme {:?} in the query", grammeme)
+            }
+            TagQueryError::TooDeep { pos } => write!(f, "too deep nesting at {} in the query", pos),
+        }
+    }
+}
+
+impl Error for TagQueryError {}
+
+impl TagQuery {
+    /// Parses the query without checking its grammemes, see `check`.
+    pub fn parse(query: &str) -> Result<Self, TagQueryError> {
+        let mut parser = Parser {
+            query,
+            chars: query.char_indices().peekable(),
+            depth: 0,
+        };
+        let result = parser.parse_or()?;
+        match parser.peek() {
+            Some((pos, ch)) => Err(TagQueryError::UnexpectedChar { pos, ch }),
+            None => Ok(result),
+        }
+    }
+
+    /// Checks that all grammemes of the query are known to the dictionary
+    /// or to its analyzer units.
+    ///
+    /// ```
+    /// use rsmorphy::opencorpora::query::{TagQuery, TagQueryError};
+    /// use rsmorphy::opencorpora::Dictionary;
+    ///
+    /// let dict = Dictionary::from_file(rsmorphy::dict_ru::DICT_PATH);
+    /// assert_eq!(TagQuery::parse("NOUN & !LATN").unwrap().check(&dict), Ok(()));
+    /// assert_eq!(
+    ///     TagQuery::parse("NOUN & !nomm").unwrap().check(&dict),
+    ///     Err(TagQueryError::UnknownGrammeme("nomm".to_owned()))
+    /// );
+    /// ```
+    pub fn check(&self, dict: &Dictionary) -> Result<(), TagQueryError> {
+        match self {
+            TagQuery::Grammeme(grammeme) if dict.grammeme_metas.contains_key(grammeme) => Ok(()),
+            TagQuery::Grammeme(grammeme) => {
+                Err(TagQueryError::UnknownGrammeme(grammeme.as_str().to_owned()))
+            }
+            TagQuery::Not(query) => query.check(dict),
+            TagQuery::And(queries) | TagQuery::Or(queries) => {
+                queries.iter().try_for_each(|query| query.check(dict))
+            }
+        }
+    }
+
+    fn fmt_operand(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TagQuery::And(_) | TagQuery::Or(_) => write!(f, "({})", self),
+            _ => write!(f, "{}", self),
+        }
+    }
+}
+
+impl fmt::Display for TagQuery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (queries, sep) = match self {
+            TagQuery::Grammeme(grammeme) => return write!(f, "{}", grammeme),
+            TagQuery::Not(query) => {
+                write!(f, "!")?;
+                return query.fmt_operand(f);
+            }
+            TagQuery::And(queries) => (queries, " & "),
+            TagQuery::Or(queries) => (queries, " | "),
+        };
+        for (i, query) in queries.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{}", sep)?;
+            }
+            query.fmt_operand(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl OpencorporaTagReg {
+    pub fn matches(&self, query: &TagQuery) -> bool {
+        match query {
+            TagQuery::Grammeme(grammeme) => self.grammemes.set.contains(grammeme),
+            TagQuery::Not(query) => !self.matches(query),
+            TagQuery::And(queries) => queries.iter().all(|query| self.matches(query)),
+            TagQuery::Or(queries) => queries.iter().any(|query| self.matches(query)),
+        }
+    }
+}
+
+struct Parser<'a> {
+    query: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+    /// The nesting of `!` and parentheses at the current character
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// The next character which is not a whitespace.
+    fn peek(&mut self) -> Option<(usize, char)> {
+        while let Some(&(_, ch)) = self.chars.peek() {
+            if !ch.is_whitespace() {
+                break;
+            }
+            self.chars.next();
+        }
+        self.chars.peek().cloned()
+    }
+
+    /// Takes the `!` or the opening parenthesis at `pos`.
+    fn enter(&mut self, pos: usize) -> Result<(), TagQueryError> {
+        if self.depth == MAX_DEPTH {
+            return Err(TagQueryError::TooDeep { pos });
+        }
+        self.depth += 1;
+        self.chars.next();
+        Ok(())
+    }
+
+    fn parse_or(&mut self) -> Result<TagQuery, TagQueryError> {
+        let mut queries = vec![self.parse_and()?];
+        while let Some((_, '|')) = self.peek() {
+            self.chars.next();
+            queries.push(self.parse_and()?);
+        }
+        Ok(flatten(queries, TagQuery::Or))
+    }
+
+    fn parse_and(&mut self) -> Result<TagQuery, TagQueryError> {
+        let mut queries = vec![self.parse_unary()?];
+        while let Some((_, '&')) = self.peek() {
+            self.chars.next();
+            queries.push(self.parse_unary()?);
+        }
+        Ok(flatten(queries, TagQuery::And))
+    }
+
+    fn parse_unary(&mut self) -> Result<TagQuery, TagQueryError> {
+        match self.peek() {
+            None => Err(TagQueryError::UnexpectedEnd),
+            Some((pos, '!')) => {
+                self.enter(pos)?;
+                let query = self.parse_unary()?;
+                self.depth -= 1;
+                Ok(TagQuery::Not(Box::new(query)))
+            }
+            Some((pos, '(')) => {
+                self.enter(pos)?;
+                let query = self.parse_or()?;
+                self.depth -= 1;
+                match self.peek() {
+                    Some((_, ')')) => {
+                        self.chars.next();
+                        Ok(query)
+                    }
+                    Some((pos, ch)) => Err(TagQueryError::UnexpectedChar { pos, ch }),
+                    None => Err(TagQueryError::UnexpectedEnd),
+                }
+            }
+            Some((start, ch)) if is_grammeme_char(ch) => {
+                let mut end = start;
+                while let Some(&(pos, ch)) = self.chars.peek() {
+                    if !is_grammeme_char(ch) {
+                        break;
+                    }
+                    end = pos + ch.len_utf8();
+                    self.chars.next();
+                }
+                Ok(TagQuery::Grammeme(Grammeme::new(&self.query[start..end])))
+            }
+            Some((pos, ch)) => Err(TagQueryError::UnexpectedChar { pos, ch }),
+        }
+    }
+}
+
+fn flatten<F>(mut queries: Vec<TagQuery>, make: F) -> TagQuery
+where
+    F: Fn(Vec<TagQuery>) -> TagQuery,
+{
+    if queries.len() == 1 {
+        queries.pop().unwrap()
+    } else {
+        make(queries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            TagQuery::parse("NOUN & (nomn | "),
+            Err(TagQueryError::UnexpectedEnd)
+        );
+        assert_eq!(
+            TagQuery::parse("NOUN nomn"),
+            Err(TagQueryError::UnexpectedChar { pos: 5, ch: 'n' })
+        );
+        let query = TagQuery::parse("!(NOUN | ADJF) & (sing|plur)&!!V-ey").unwrap();
+        assert_eq!(query.to_string(), "!(NOUN | ADJF) & (sing | plur) & !!V-ey");
+        assert_eq!(TagQuery::parse(&query.to_string()), Ok(query));
+    }
+
+    #[test]
+    fn max_depth() {
+        let deep = |depth, open: &str, close: &str| {
+            format!("{}NOUN{}", open.repeat(depth), close.repeat(depth))
+        };
+        assert!(TagQuery::parse(&deep(MAX_DEPTH, "!", "")).is_ok());
+        assert!(TagQuery::parse(&deep(MAX_DEPTH, "(", ")")).is_ok());
+        assert!(TagQuery::parse(&deep(MAX_DEPTH / 2, "!(", ")")).is_ok());
+        assert_eq!(
+            TagQuery::parse(&deep(MAX_DEPTH + 1, "(", ")")),
+            Err(TagQueryError::TooDeep { pos: MAX_DEPTH })
+        );
+        assert_eq!(
+            TagQuery::parse(&deep(1_000_000, "!", "")),
+            Err(TagQueryError::TooDeep { pos: MAX_DEPTH })
+        );
+    }
+}
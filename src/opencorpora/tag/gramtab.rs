@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Index;
+use std::sync::OnceLock;
+
+use crate::bundle::StringTable;
+use crate::opencorpora::grammeme::{Grammeme, GrammemeBits, GrammemeTable};
+use crate::opencorpora::tag::OpencorporaTagReg;
+
+/// Tags of a dictionary, indexed by `ParadigmEntry::tag_id`.
+///
+/// The strings of both spellings borrow from the mapping of a bundle,
+/// and a tag is parsed the first time it's used, so each process owns
+/// only the tags it has come across. Tags are interned: entries with
+/// the same string, and tags looked up with `find`, share one parsed tag.
+#[derive(Clone)]
+pub struct Gramtab {
+    int: StringTable,
+    ext: StringTable,
+    table: GrammemeTable,
+    /// Indices of grammemes of the dictionary, see `OpencorporaTagReg::bits`
+    indices: HashMap<Grammeme, usize>,
+    tags: Vec<OnceLock<Box<OpencorporaTagReg>>>,
+    interned: OnceLock<Interned>,
+}
+
+#[derive(Debug, Clone)]
+struct Interned {
+    /// Indices of the tags sorted by their strings
+    sorted: Vec<u32>,
+    /// The first index of a tag with the same string, for each index
+    first: Vec<u32>,
+}
+
+impl Interned {
+    fn new(strings: &StringTable) -> Self {
+        let mut sorted: Vec<u32> = (0..strings.len() as u32).collect();
+        // The sort is stable, so the first of equal strings comes first.
+        sorted.sort_by(|&a, &b| strings[a as usize].cmp(&strings[b as usize]));
+        let mut first = vec![0; strings.len()];
+        for run in sorted.chunk_by(|&a, &b| strings[a as usize] == strings[b as usize]) {
+            for &idx in run {
+                first[idx as usize] = run[0];
+            }
+        }
+        Interned { sorted, first }
+    }
+}
+
+impl Gramtab {
+    /// `int` and `ext` are the same tags spelled with opencorpora-int
+    /// and opencorpora-ext grammemes.
+    pub fn new(
+        int: StringTable,
+        ext: StringTable,
+        table: GrammemeTable,
+        indices: HashMap<Grammeme, usize>,
+    ) -> Self {
+        assert_eq!(int.len(), ext.len(), "Gramtab spellings differ in length");
+        let tags = (0..int.len()).map(|_| OnceLock::new()).collect();
+        Gramtab {
+            int,
+            ext,
+            table,
+            indices,
+            tags,
+            interned: OnceLock::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.tags.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    pub fn get(&self, idx: usize) -> Option<&OpencorporaTagReg> {
+        let idx = *self.interned().first.get(idx)? as usize;
+        Some(self.tags[idx].get_or_init(|| Box::new(self.parse(idx))))
+    }
+
+    /// The tag of the gramtab spelled with opencorpora-int grammemes.
+    pub fn find(&self, string: &str) -> Option<&OpencorporaTagReg> {
+        let sorted = &self.interned().sorted;
+        let pos = sorted
+            .binary_search_by(|&idx| self.int[idx as usize].cmp(string))
+            .ok()?;
+        self.get(sorted[pos] as usize)
+    }
+
+    fn interned(&self) -> &Interned {
+        self.interned.get_or_init(|| Interned::new(&self.int))
+    }
+
+    fn parse(&self, idx: usize) -> OpencorporaTagReg {
+        let tag = OpencorporaTagReg::with_table(&self.int[idx], &self.table);
+        let bits = tag
+            .grammemes
+            .set
+            .iter()
+            .map(|grammeme| self.indices.get(grammeme).cloned())
+            .collect::<Option<GrammemeBits>>();
+        OpencorporaTagReg {
+            ext_string: Some(self.ext[idx].to_owned()),
+            bits,
+            ..tag
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &OpencorporaTagReg> {
+        (0..self.len()).map(move |idx| &self[idx])
+    }
+
+    /// The opencorpora-int spellings of the tags.
+    pub fn int_strings(&self) -> &StringTable {
+        &self.int
+    }
+
+    /// The opencorpora-ext spellings of the tags.
+    pub fn ext_strings(&self) -> &StringTable {
+        &self.ext
+    }
+}
+
+impl Index<usize> for Gramtab {
+    type Output = OpencorporaTagReg;
+
+    fn index(&self, idx: usize) -> &OpencorporaTagReg {
+        self.get(idx).expect("tag index out of range")
+    }
+}
+
+impl fmt::Debug for Gramtab {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.int.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use super::*;
+
+    #[test]
+    fn interned() {
+        let strings = ["NOUN sing", "VERB", "NOUN sing", "ADJF"];
+        let gramtab = Gramtab::new(
+            StringTable::new(&strings),
+            StringTable::new(&strings),
+            GrammemeTable::default(),
+            HashMap::new(),
+        );
+        assert!(ptr::eq(&gramtab[0], &gramtab[2]));
+        assert!(!ptr::eq(&gramtab[0], &gramtab[1]));
+        assert!(ptr::eq(gramtab.find("NOUN sing").unwrap(), &gramtab[2]));
+        assert!(ptr::eq(gramtab.find("ADJF").unwrap(), &gramtab[3]));
+        assert_eq!(gramtab.find("NOUN"), None);
+        assert_eq!(gramtab.get(4), None);
+        let strings: Vec<&str> = gramtab.iter().map(|tag| tag.string.as_str()).collect();
+        assert_eq!(strings, ["NOUN sing", "VERB", "NOUN sing", "ADJF"]);
+    }
+}
@@ -1,5 +1,9 @@
+pub mod gramtab;
 pub mod reg;
 pub mod tag;
+pub mod tagset;
 
+pub use self::gramtab::Gramtab;
 pub use self::reg::OpencorporaTagReg;
 pub use self::tag::OTag;
+pub use self::tagset::Tagset;
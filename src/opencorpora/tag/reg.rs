@@ -1,17 +1,29 @@
+use std::borrow::Cow;
 use std::collections::HashSet;
+use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::str::FromStr;
 
 use maplit::hashset;
 
 use crate::analyzer::MorphAnalyzer;
-use crate::opencorpora::grammeme::GrammemeSet;
+use crate::language::Language;
+use crate::opencorpora::dictionary::Dictionary;
+use crate::opencorpora::grammeme::{
+    Grammeme, GrammemeBits, GrammemeSet, GrammemeTable, InvalidGrammeme,
+};
 use crate::opencorpora::kind::*;
+use crate::opencorpora::tag::Tagset;
 
 //#[derive(Deserialize)]
 #[derive(Debug, Clone, Eq)]
 pub struct OpencorporaTagReg {
     pub string: String,
+    /// The opencorpora-ext spelling, kept for tags of the dictionary.
+    pub ext_string: Option<String>,
     pub grammemes: GrammemeSet,
+    /// Grammemes as bits of the dictionary, kept for tags of the dictionary.
+    pub bits: Option<GrammemeBits>,
 
     pub pos: Option<PartOfSpeach>,
     pub animacy: Option<Animacy>,
@@ -26,7 +38,8 @@ pub struct OpencorporaTagReg {
     pub transitivity: Option<Transitivity>,
     pub voice: Option<Voice>,
 
-    pub has_apro: bool,
+    /// Grammemes which don't belong to any category.
+    pub flags: Flags,
 }
 
 impl Hash for OpencorporaTagReg {
@@ -41,53 +54,146 @@ impl PartialEq for OpencorporaTagReg {
     }
 }
 
+impl fmt::Display for OpencorporaTagReg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.string)
+    }
+}
+
+/// Unlike `new`, checks the grammemes of the tag.
+///
+/// ```
+/// use rsmorphy::opencorpora::OpencorporaTagReg;
+///
+/// let tag: OpencorporaTagReg = "NOUN,anim,masc sing,nomn".parse().unwrap();
+/// assert_eq!(tag.to_string(), "NOUN,anim,masc sing,nomn");
+/// assert!("NOUN,anim,masc sing,".parse::<OpencorporaTagReg>().is_err());
+/// ```
+impl FromStr for OpencorporaTagReg {
+    type Err = InvalidGrammeme;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        GrammemeSet::from_str(s)?;
+        Ok(OpencorporaTagReg::new(s))
+    }
+}
+
 impl OpencorporaTagReg {
+    /// Parses the tag with the grammeme categories known at compile time;
+    /// see `with_table` for the ones of a dictionary, and `Dictionary::tag`
+    /// for the tags it already has.
     pub fn new<S>(s: S) -> Self
     where
         S: Into<String>,
     {
-        let string = s.into();
-
-        let grammemes = GrammemeSet::new(&string);
+        OpencorporaTagReg::parse(s.into(), GrammemeKind::from_grammeme)
+    }
 
-        let pos = PartOfSpeach::try_from_str(&string);
-        let animacy = Animacy::try_from_str(&string);
-        let aspect = Aspect::try_from_str(&string);
-        let case = Case::try_from_str(&string);
-        let gender = Gender::try_from_str(&string);
-        let involvement = Involvement::try_from_str(&string);
-        let mood = Mood::try_from_str(&string);
-        let number = Number::try_from_str(&string);
-        let person = Person::try_from_str(&string);
-        let tense = Tense::try_from_str(&string);
-        let transitivity = Transitivity::try_from_str(&string);
-        let voice = Voice::try_from_str(&string);
+    /// Parses the tag with categories of grammemes of the dictionary.
+    pub fn with_table<S>(s: S, table: &GrammemeTable) -> Self
+    where
+        S: Into<String>,
+    {
+        OpencorporaTagReg::parse(s.into(), |grammeme| table.get(grammeme))
+    }
 
-        let has_apro = string.contains("Apro");
+    fn parse<F>(string: String, kind_of: F) -> Self
+    where
+        F: Fn(&str) -> Option<GrammemeKind>,
+    {
+        let mut tag = OpencorporaTagReg {
+            grammemes: GrammemeSet::new(&string),
+            string: String::new(),
+            ext_string: None,
+            bits: None,
+            flags: Flags::new(),
+            pos: None,
+            animacy: None,
+            aspect: None,
+            case: None,
+            gender: None,
+            involvement: None,
+            mood: None,
+            number: None,
+            person: None,
+            tense: None,
+            transitivity: None,
+            voice: None,
+        };
+        // The first grammeme of a category wins.
+        for grammeme in string.split(&[',', ' '][..]) {
+            match kind_of(grammeme) {
+                Some(GrammemeKind::Pos(v)) => tag.pos = tag.pos.or(Some(v)),
+                Some(GrammemeKind::Animacy(v)) => tag.animacy = tag.animacy.or(Some(v)),
+                Some(GrammemeKind::Aspect(v)) => tag.aspect = tag.aspect.or(Some(v)),
+                Some(GrammemeKind::Case(v)) => tag.case = tag.case.or(Some(v)),
+                Some(GrammemeKind::Gender(v)) => tag.gender = tag.gender.or(Some(v)),
+                Some(GrammemeKind::Involvement(v)) => tag.involvement = tag.involvement.or(Some(v)),
+                Some(GrammemeKind::Mood(v)) => tag.mood = tag.mood.or(Some(v)),
+                Some(GrammemeKind::Number(v)) => tag.number = tag.number.or(Some(v)),
+                Some(GrammemeKind::Person(v)) => tag.person = tag.person.or(Some(v)),
+                Some(GrammemeKind::Tense(v)) => tag.tense = tag.tense.or(Some(v)),
+                Some(GrammemeKind::Transitivity(v)) => {
+                    tag.transitivity = tag.transitivity.or(Some(v))
+                }
+                Some(GrammemeKind::Voice(v)) => tag.voice = tag.voice.or(Some(v)),
+                Some(GrammemeKind::Flag(flag)) => tag.flags.insert(flag),
+                None => {}
+            }
+        }
+        tag.string = string;
+        tag
+    }
 
+    /// A tag with both spellings, like in the gramtabs of the dictionary.
+    pub fn with_ext<S, E>(s: S, ext: E) -> Self
+    where
+        S: Into<String>,
+        E: Into<String>,
+    {
         OpencorporaTagReg {
-            string,
-            grammemes,
-            has_apro,
-            pos,
-            animacy,
-            aspect,
-            case,
-            gender,
-            involvement,
-            mood,
-            number,
-            person,
-            tense,
-            transitivity,
-            voice,
+            ext_string: Some(ext.into()),
+            ..OpencorporaTagReg::new(s)
+        }
+    }
+
+    /// The opencorpora-ext spelling of the tag; tags made outside
+    /// of the dictionary are translated with grammeme aliases.
+    pub fn get_ext_string(&self, dict: &Dictionary) -> Cow<'_, str> {
+        match self.ext_string {
+            Some(ref ext) => Cow::Borrowed(ext),
+            None => Cow::Owned(dict.translate_to_ext(&self.string)),
         }
     }
 
+    pub fn get_string(&self, tagset: Tagset, dict: &Dictionary) -> Cow<'_, str> {
+        match tagset {
+            Tagset::Internal => Cow::Borrowed(&self.string),
+            Tagset::External => self.get_ext_string(dict),
+        }
+    }
+
+    /// ```
+    /// use rsmorphy::opencorpora::kind::{Case, Flag};
+    /// use rsmorphy::opencorpora::OpencorporaTagReg;
+    ///
+    /// let tag = OpencorporaTagReg::new("NOUN,anim,masc,Surn sing,gent");
+    /// assert!(tag.has(Flag::Surn));
+    /// assert!(tag.has(Case::Gent));
+    /// assert!(tag.has("anim"));
+    /// assert!(!tag.has(Flag::Erro));
+    /// ```
+    pub fn has<G>(&self, grammeme: G) -> bool
+    where
+        G: Into<Grammeme>,
+    {
+        self.grammemes.set.contains(&grammeme.into())
+    }
+
     pub fn is_productive(&self) -> bool {
         self.pos
             .map(|pos| pos.is_productive())
-            .unwrap_or_else(|| !self.has_apro)
+            .unwrap_or_else(|| !self.flags.contains(Flag::Apro))
     }
 
     pub fn prepare_required(&self, morph: &MorphAnalyzer, required: &GrammemeSet) -> GrammemeSet {
@@ -100,12 +206,34 @@ impl OpencorporaTagReg {
         GrammemeSet { set: new_grammemes }
     }
 
+    /// `prepare_required` with grammemes as bits of the dictionary;
+    /// `None` if the tag has grammemes unknown to the dictionary.
+    pub fn prepare_required_bits(
+        &self,
+        morph: &MorphAnalyzer,
+        required: GrammemeBits,
+    ) -> Option<GrammemeBits> {
+        let dict = &morph.dict;
+        let new_grammemes = required
+            .iter()
+            .map(|index| &dict.grammeme_metas[&dict.grammeme_indices[index]])
+            .fold(dict.tag_bits(self)? | required, |new_grammemes, meta| {
+                new_grammemes - meta.incompatible_bits
+            });
+        Some(new_grammemes)
+    }
+
+    /// Grammemes of the form agreeing with the number in Russian,
+    /// see `numeral_agreement_grammemes_in`.
     pub fn numeral_agreement_grammemes(&self, num: usize) -> GrammemeSet {
-        let index = match num {
-            num if (num % 10 == 1) && (num % 100 != 11) => 0,
-            num if (num % 10 >= 2) && (num % 10 <= 4) && (num % 100 < 10 || num % 100 >= 20) => 1,
-            _ => 2,
-        };
+        self.numeral_agreement_grammemes_in(Language::Russian, num)
+    }
+
+    /// Grammemes of the form agreeing with the number in the language.
+    pub fn numeral_agreement_grammemes_in(&self, language: Language, num: usize) -> GrammemeSet {
+        let index = language.plural_form(num);
+        // "2 яблока", but "2 яблука" in Ukrainian are in the plural.
+        let few_in_plural = language == Language::Ukrainian;
 
         let x = match self.pos {
             Some(PartOfSpeach::Noun) | Some(PartOfSpeach::Adjf) | Some(PartOfSpeach::Prtf) => {
@@ -124,13 +252,11 @@ impl OpencorporaTagReg {
                         _ => Some((Number::Sing, Some(Case::Accs))),
                     },
 
-                    Some(PartOfSpeach::Noun) if index == 1 => {
+                    Some(PartOfSpeach::Noun) if index == 1 && !few_in_plural => {
                         Some((Number::Sing, Some(Case::Gent)))
                     }
 
-                    Some(PartOfSpeach::Adjf) | Some(PartOfSpeach::Prtf)
-                        if index == 1 && self.gender == Some(Gender::Femn) =>
-                    {
+                    _ if index == 1 && (few_in_plural || self.gender == Some(Gender::Femn)) => {
                         Some((Number::Plur, Some(Case::Nomn)))
                     }
 
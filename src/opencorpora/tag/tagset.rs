@@ -0,0 +1,9 @@
+/// The spelling of tags.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tagset {
+    /// opencorpora-int, Latin grammemes like "NOUN,anim,masc sing,nomn"
+    #[default]
+    Internal,
+    /// opencorpora-ext, Cyrillic grammemes like "СУЩ,од,мр ед,им"
+    External,
+}
@@ -0,0 +1,257 @@
+//! Conversion of OpenCorpora tags to Universal Dependencies, following
+//! the conventions of the Russian UD treebanks.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::opencorpora::kind::{Flag, FlagGroup, PartOfSpeach};
+use crate::opencorpora::{Grammeme, GrammemeSet, OpencorporaTagReg};
+
+/// Grammemes with their UD features; the first grammeme of a feature value
+/// is the one the reverse mapping yields.
+const FEATURES: &[(&str, &str, &str)] = &[
+    ("Abbr", "Abbr", "Yes"),
+    ("anim", "Animacy", "Anim"),
+    ("inan", "Animacy", "Inan"),
+    ("perf", "Aspect", "Perf"),
+    ("impf", "Aspect", "Imp"),
+    ("nomn", "Case", "Nom"),
+    ("gent", "Case", "Gen"),
+    ("gen1", "Case", "Gen"),
+    ("gen2", "Case", "Par"),
+    ("datv", "Case", "Dat"),
+    ("accs", "Case", "Acc"),
+    ("acc2", "Case", "Acc"),
+    ("ablt", "Case", "Ins"),
+    ("loct", "Case", "Loc"),
+    ("loc1", "Case", "Loc"),
+    ("loc2", "Case", "Loc"),
+    ("voct", "Case", "Voc"),
+    ("Supr", "Degree", "Sup"),
+    ("masc", "Gender", "Masc"),
+    ("femn", "Gender", "Fem"),
+    ("neut", "Gender", "Neut"),
+    ("indc", "Mood", "Ind"),
+    ("impr", "Mood", "Imp"),
+    ("sing", "Number", "Sing"),
+    ("plur", "Number", "Plur"),
+    ("1per", "Person", "1"),
+    ("2per", "Person", "2"),
+    ("3per", "Person", "3"),
+    ("pres", "Tense", "Pres"),
+    ("past", "Tense", "Past"),
+    ("futr", "Tense", "Fut"),
+    ("actv", "Voice", "Act"),
+    ("pssv", "Voice", "Pass"),
+];
+
+/// UPOS and FEATS of a CoNLL-U token.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UdTag {
+    pub upos: &'static str,
+    /// Feature-value pairs sorted by the feature name.
+    pub feats: Vec<(&'static str, &'static str)>,
+}
+
+impl UdTag {
+    /// FEATS as in CoNLL-U: "Case=Nom|Number=Sing", or "_" if there are none.
+    pub fn feats_string(&self) -> String {
+        if self.feats.is_empty() {
+            return "_".to_owned();
+        }
+        self.feats
+            .iter()
+            .map(|(feature, value)| format!("{}={}", feature, value))
+            .collect::<Vec<_>>()
+            .join("|")
+    }
+}
+
+impl fmt::Display for UdTag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}\t{}", self.upos, self.feats_string())
+    }
+}
+
+impl OpencorporaTagReg {
+    /// ```
+    /// use rsmorphy::opencorpora::OpencorporaTagReg;
+    ///
+    /// let tag = OpencorporaTagReg::new("PRTF,perf,tran,past,pssv inan,femn,sing,nomn");
+    /// let ud = tag.to_ud();
+    /// assert_eq!(ud.upos, "VERB");
+    /// assert_eq!(
+    ///     ud.feats_string(),
+    ///     "Animacy=Inan|Aspect=Perf|Case=Nom|Gender=Fem|Number=Sing|Tense=Past|VerbForm=Part|Voice=Pass"
+    /// );
+    /// assert_eq!(OpencorporaTagReg::new("NUMB,intg").to_ud().feats_string(), "NumForm=Digit");
+    /// assert_eq!(OpencorporaTagReg::new("PNCT").to_ud().to_string(), "PUNCT\t_");
+    /// ```
+    pub fn to_ud(&self) -> UdTag {
+        use crate::opencorpora::kind::PartOfSpeach::*;
+
+        let has = |grammeme: &str| self.has(grammeme);
+        let mut feats = Vec::new();
+        let upos = match self.pos {
+            Some(Noun) if self.flags.intersects(FlagGroup::Proper) => "PROPN",
+            Some(Noun) => "NOUN",
+            Some(Adjf) if self.flags.contains(Flag::Apro) => "DET",
+            Some(Adjf) => "ADJ",
+            Some(Adjs) => {
+                feats.push(("Variant", "Short"));
+                "ADJ"
+            }
+            Some(Comp) => {
+                feats.push(("Degree", "Cmp"));
+                "ADJ"
+            }
+            Some(Verb) | Some(Infn) | Some(Prtf) | Some(Prts) | Some(Grnd) => {
+                feats.push(("VerbForm", verb_form(self.pos)));
+                if self.pos == Some(Prts) {
+                    feats.push(("Variant", "Short"));
+                }
+                "VERB"
+            }
+            Some(Numr) => "NUM",
+            Some(Advb) | Some(Pred) => "ADV",
+            Some(Npro) => "PRON",
+            Some(Prep) => "ADP",
+            Some(Conj) => "CCONJ",
+            Some(Prcl) => "PART",
+            Some(Intj) => "INTJ",
+            None if has("NUMB") => {
+                feats.push(("NumForm", "Digit"));
+                "NUM"
+            }
+            None if has("ROMN") => {
+                feats.push(("NumForm", "Roman"));
+                "NUM"
+            }
+            None if has("PNCT") => "PUNCT",
+            None if has("LATN") => {
+                feats.push(("Foreign", "Yes"));
+                "X"
+            }
+            None => "X",
+        };
+        if upos == "ADJ" && !self.flags.contains(Flag::Supr) && self.pos != Some(Comp) {
+            feats.push(("Degree", "Pos"));
+        }
+        feats.extend(
+            FEATURES
+                .iter()
+                .filter(|&&(grammeme, _, _)| has(grammeme))
+                .map(|&(_, feature, value)| (feature, value)),
+        );
+        feats.sort_by_key(|&(feature, _)| feature.to_lowercase());
+        feats.dedup();
+        UdTag { upos, feats }
+    }
+}
+
+fn verb_form(pos: Option<PartOfSpeach>) -> &'static str {
+    match pos {
+        Some(PartOfSpeach::Infn) => "Inf",
+        Some(PartOfSpeach::Prtf) | Some(PartOfSpeach::Prts) => "Part",
+        Some(PartOfSpeach::Grnd) => "Conv",
+        _ => "Fin",
+    }
+}
+
+/// Builds grammemes from UPOS and FEATS in the CoNLL-U form, e.g. to inflect
+/// a word to the form a dependency parser expects; unknown features are skipped.
+///
+/// ```
+/// use rsmorphy::opencorpora::ud::grammemes_from_ud;
+/// use rsmorphy::opencorpora::GrammemeSet;
+///
+/// assert_eq!(
+///     grammemes_from_ud("VERB", "Aspect=Perf|VerbForm=Inf"),
+///     GrammemeSet::new("INFN,perf")
+/// );
+/// assert_eq!(
+///     grammemes_from_ud("NOUN", "Case=Ins|Number=Plur|Typo=Yes"),
+///     GrammemeSet::new("NOUN,ablt,plur")
+/// );
+/// ```
+pub fn grammemes_from_ud(upos: &str, feats: &str) -> GrammemeSet {
+    let feats: Vec<(&str, &str)> = feats
+        .split('|')
+        .filter_map(|feat| {
+            let pos = feat.find('=')?;
+            Some((&feat[..pos], &feat[pos + 1..]))
+        })
+        .collect();
+    let feat = |feature: &str| {
+        feats
+            .iter()
+            .find(|&&(f, _)| f == feature)
+            .map(|&(_, value)| value)
+    };
+
+    let pos = match upos {
+        "NOUN" | "PROPN" => "NOUN",
+        "ADJ" if feat("Degree") == Some("Cmp") => "COMP",
+        "ADJ" if feat("Variant") == Some("Short") => "ADJS",
+        "ADJ" | "DET" => "ADJF",
+        "VERB" | "AUX" => match (feat("VerbForm"), feat("Variant")) {
+            (Some("Inf"), _) => "INFN",
+            (Some("Part"), Some("Short")) => "PRTS",
+            (Some("Part"), _) => "PRTF",
+            (Some("Conv"), _) => "GRND",
+            _ => "VERB",
+        },
+        "NUM" => match feat("NumForm") {
+            Some("Digit") => "NUMB",
+            Some("Roman") => "ROMN",
+            _ => "NUMR",
+        },
+        "ADV" => "ADVB",
+        "PRON" => "NPRO",
+        "ADP" => "PREP",
+        "CCONJ" | "SCONJ" => "CONJ",
+        "PART" => "PRCL",
+        "INTJ" => "INTJ",
+        "PUNCT" | "SYM" => "PNCT",
+        "X" if feat("Foreign") == Some("Yes") => "LATN",
+        _ => "UNKN",
+    };
+
+    let mut set = HashSet::new();
+    set.insert(Grammeme::new(pos));
+    if upos == "DET" {
+        set.insert(Grammeme::new("Apro"));
+    }
+    for (feature, value) in feats {
+        let grammeme = FEATURES
+            .iter()
+            .find(|&&(_, f, v)| f == feature && v == value)
+            .map(|&(grammeme, _, _)| grammeme);
+        if let Some(grammeme) = grammeme {
+            set.insert(Grammeme::new(grammeme));
+        }
+    }
+    GrammemeSet { set }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_ud() {
+        let tag = OpencorporaTagReg::new("NOUN,anim,femn sing,nomn");
+        let ud = tag.to_ud();
+        assert_eq!(ud.upos, "NOUN");
+        assert_eq!(
+            ud.feats_string(),
+            "Animacy=Anim|Case=Nom|Gender=Fem|Number=Sing"
+        );
+        assert_eq!(
+            grammemes_from_ud(ud.upos, &ud.feats_string()),
+            tag.grammemes
+        );
+        let tag = OpencorporaTagReg::new("VERB,perf,intr plur,past,indc");
+        assert_eq!(tag.to_ud().upos, "VERB");
+    }
+}